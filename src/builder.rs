@@ -1,45 +1,224 @@
-use crate::param::{self, Parameters};
-use reqwest::{RequestBuilder, Response, header::CONTENT_TYPE};
-use crate::error::ClientError;
+use crate::param::{self, Number, Parameters};
+use reqwest::{RequestBuilder, Response, StatusCode, header::CONTENT_TYPE};
+use crate::error::{BinanceErrorCode, ClientError, Error, Kind, ServerError, UnknownExecutionError};
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 use std::marker::PhantomData;
+use std::error::Error as _;
+use tokio::time::sleep;
 use chrono::{DateTime, TimeZone};
 use crate::types::*;
+use crate::model::*;
+use crate::client::{self, Settings, WsApi};
+use crate::credentials::{ApiKey, Security};
+use crate::rate_limit::Cost;
+use crate::retry::{self, Idempotency};
+use crate::rules::{ExchangeRules, ValidationMode};
 use log::warn;
 
 pub struct ParamBuilder<'a, 'b, T> {
     _marker: PhantomData<T>,
     params: Parameters<'a>,
     builder: RequestBuilder,
-    api_key: Option<&'b str>,
-    secret_key: Option<&'b str>,
-
+    security: Security<'b>,
+    validation: Option<(ExchangeRules, ValidationMode)>,
+    settings: &'b Settings,
+    ws_api: Option<&'b WsApi>,
 }
 
 impl<'a, 'b, T> ParamBuilder<'a, 'b, T> {
-    pub(super) fn new(params: Parameters<'a>, builder: RequestBuilder, api_key: Option<&'b str>, secret_key: Option<&'b str>) -> Self {
+    pub(super) fn new(
+        params: Parameters<'a>,
+        builder: RequestBuilder,
+        security: Security<'b>,
+        settings: &'b Settings
+    ) -> Self {
         Self {
             _marker: PhantomData,
             params,
             builder,
-            api_key,
-            secret_key
+            security,
+            validation: None,
+            settings,
+            ws_api: None
         }
     }
+    /// Send the request over the WebSocket API instead of REST.
+    pub(super) fn over(mut self, ws_api: &'b WsApi) -> Self {
+        self.ws_api = Some(ws_api);
+        self
+    }
 
     pub async fn text(self) -> crate::error::Result<String> {
+        if let Some(ws_api) = self.ws_api {
+            return Ok(self.call(ws_api).await?.to_string());
+        }
+
         let text = self.response().await?.text().await?;
         Ok(text)
     }
 
     pub async fn json<J: DeserializeOwned>(self) -> crate::error::Result<J> {
+        if let Some(ws_api) = self.ws_api {
+            return Ok(serde_json::from_value(self.call(ws_api).await?)?);
+        }
+
         let json = self.response().await?.json::<J>().await?;
         Ok(json)
     }
 
-    async fn response(self) -> crate::error::Result<Response> {
-        let res = self.builder()?.send().await?;
+    async fn response(mut self) -> crate::error::Result<Response> {
+        let settings = self.settings;
+        let policy = settings.retry.as_ref();
+        let time_sync = settings.time_sync.as_ref().filter(|time_sync| time_sync.resyncs_on_error());
+
+        // Cloning will never panic since the client does not set a body
+        let request = self.builder.try_clone().expect("Unsupported body").build()?;
+        let idempotency = Idempotency::of(&request);
+        // A looked up order has no fills, so it can't stand in for a FULL response,
+        // which spot and margin MARKET and LIMIT orders default to.
+        let full = match self.params.new_order_resp_type {
+            Some(resp_type) => matches!(resp_type, param::OrderRespType::Full),
+            None => matches!(request.url().path(), "/api/v3/order" | "/sapi/v1/margin/order"),
+        };
+        let reconcile = idempotency == Idempotency::NewOrder && !full && policy.is_some_and(|policy| policy.retry_orders);
+
+        // New orders need a client order id to be looked up after a server error
+        match idempotency {
+            Idempotency::NewOrder if self.params.new_client_order_id.is_none() => {
+                self.params.new_client_order_id = Some(retry::client_order_id().into());
+            }
+            Idempotency::NewOrderList if self.params.list_client_order_id.is_none() => {
+                self.params.list_client_order_id = Some(retry::client_order_id().into());
+            }
+            _ => {}
+        }
+
+        let mut resynced = false;
+        let mut retries = 0;
+        let mut delay = policy.map(|policy| policy.initial_delay).unwrap_or_default();
+
+        loop {
+            let next = match (time_sync, policy) {
+                (None, None) => None,
+                _ => self.try_clone(),
+            };
+            let result = self.send().await;
+
+            let next = match next {
+                Some(next) => next,
+                None => return result,
+            };
+
+            if let Err(e) = &result {
+                if let (Some(time_sync), false, Some(BinanceErrorCode::InvalidTimestamp)) = (time_sync, resynced, e.error_code()) {
+                    warn!("{}; resynchronizing time", e);
+                    time_sync.sync().await?;
+                    resynced = true;
+                    self = next;
+                    continue;
+                }
+            }
+
+            let policy = match policy {
+                Some(policy) if retries < policy.max_retries => policy,
+                _ => return result,
+            };
+
+            if !result.as_ref().err().is_some_and(|e| e.is_retryable()) {
+                return result;
+            }
+
+            match idempotency {
+                Idempotency::Safe => {}
+                Idempotency::NewOrder if reconcile => match next.reconcile().await {
+                    Ok(Some(res)) => return Ok(res),
+                    Ok(None) => {}
+                    Err(e) => {
+                        warn!("Failed to look up order: {}", e);
+                        return result;
+                    }
+                },
+                _ if refused(&result) => {}
+                _ => return result,
+            }
+
+            warn!("Request failed; retrying in {:?}", delay);
+            sleep(delay).await;
+            delay = delay.mul_f64(policy.multiplier).min(policy.max_delay);
+            retries += 1;
+            self = next;
+        }
+    }
+
+    /// Look up a new order by its client order id; `None` if it wasn't placed.
+    async fn reconcile(&self) -> crate::error::Result<Option<Response>> {
+        let client_order_id = self.params.new_client_order_id.as_deref();
+        // Cloning will never panic since the client does not set a body
+        // New orders are posted to the endpoint that looks them up
+        let mut url = self.builder.try_clone().expect("Unsupported body").build()?.url().clone();
+        url.set_query(None);
+
+        let params = Parameters {
+            symbol: self.params.symbol,
+            is_isolated: self.params.is_isolated,
+            orig_client_order_id: client_order_id,
+            ..Parameters::default()
+        };
+        let query: ParamBuilder<'_, '_, OrderStatusParams> = ParamBuilder::new(
+            params,
+            self.settings.client.get(url),
+            self.security,
+            self.settings
+        );
+
+        match query.send().await {
+            Ok(res) => Ok(Some(res)),
+            Err(e) if e.error_code() == Some(BinanceErrorCode::NoSuchOrder) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn send(mut self) -> crate::error::Result<Response> {
+        if let (Some(time_sync), Security::Signed(_)) = (&self.settings.time_sync, self.security) {
+            self.params.timestamp = Some(time_sync.timestamp().await?);
+        }
+
+        let key = match self.security {
+            Security::None => None,
+            Security::ApiKey(credentials) | Security::Signed(credentials) => Some(credentials.current()),
+        };
+        let settings = self.settings;
+        let limiter = &settings.limiter;
+        let api_key = key.as_deref().map(ApiKey::key).unwrap_or_default();
+        let client_order_id = self.params.new_client_order_id.clone().or_else(|| self.params.list_client_order_id.clone());
+        let builder = self.builder(key.as_deref())?;
+
+        let request = builder.build()?;
+        let idempotency = Idempotency::of(&request);
+        let cost = Cost::of(&request);
+        limiter.acquire(cost, api_key).await?;
+
+        let failover = &settings.failover;
+        let mut attempts = 0;
+
+        let res = loop {
+            // Cloning will never panic since the body is a string
+            let mut attempt = request.try_clone().expect("Unsupported body");
+            let index = failover.apply(&mut attempt);
+            attempts += 1;
+
+            match settings.client.execute(attempt).await {
+                // The request was not sent, so any other host may execute it
+                Err(e) if e.is_connect() && attempts < failover.len() => {
+                    warn!("{}; failing over to the next host", e);
+                    failover.fail(index);
+                }
+                res => break res?,
+            }
+        };
         let status = res.status();
+        limiter.record(cost, status, res.headers(), api_key);
 
         if status.is_success() { 
             Ok(res) 
@@ -48,26 +227,129 @@ impl<'a, 'b, T> ParamBuilder<'a, 'b, T> {
             let message = res.text().await.unwrap_or("".into());
             let err = ClientError::new(status.as_u16(), reason, &message);
             Err(err.into())
+        } else if status.is_server_error() {
+            let reason = status.canonical_reason().unwrap_or("UNKNOWN");
+            let message = res.text().await.unwrap_or("".into());
+            let err = ServerError::new(status.as_u16(), reason, &message);
+
+            match (idempotency, client_order_id) {
+                (Idempotency::NewOrder, Some(id)) | (Idempotency::NewOrderList, Some(id)) => {
+                    Err(UnknownExecutionError::new(id, err).into())
+                }
+                _ => Err(err.into()),
+            }
         } else {
             warn!("{}", status);
             Ok(res)
         }
     }
 
-    fn builder(mut self) -> crate::error::Result<RequestBuilder> {
-        let builder = if let Some(api_key) = self.api_key {
-            self.builder.header("X-MBX-APIKEY", api_key)
+    /// Send the request as a WebSocket API method; returns the result of the response.
+    async fn call(mut self, ws_api: &WsApi) -> crate::error::Result<Value> {
+        if let (Some(time_sync), Security::Signed(_)) = (&self.settings.time_sync, self.security) {
+            self.params.timestamp = Some(time_sync.timestamp().await?);
+        }
+
+        let key = match self.security {
+            Security::None => None,
+            Security::ApiKey(credentials) | Security::Signed(credentials) => Some(credentials.current()),
+        };
+        let settings = self.settings;
+        let api_key = key.as_deref().map(ApiKey::key).unwrap_or_default();
+
+        // Cloning will never panic since the client does not set a body
+        let request = self.builder.try_clone().expect("Unsupported body").build()?;
+        let idempotency = Idempotency::of(&request);
+        let method = WsApi::method(&request)
+            .ok_or_else(|| Error::new(Kind::Config, Some(format!("{} isn't a WebSocket API method", request.url().path()))))?;
+
+        // New orders need a client order id to be looked up after a server error
+        if idempotency == Idempotency::NewOrder && self.params.new_client_order_id.is_none() {
+            self.params.new_client_order_id = Some(retry::client_order_id().into());
+        }
+
+        self.prepare()?;
+
+        let signer = match self.security {
+            Security::Signed(_) => key.as_deref().and_then(ApiKey::signer),
+            _ => None,
+        };
+
+        let request = self.builder.query(&self.params).build()?;
+        let cost = Cost::of(&request);
+        settings.limiter.acquire(cost, api_key).await?;
+
+        let client_order_id = self.params.new_client_order_id.clone();
+        let params = self.params.ws_params(key.as_deref().map(ApiKey::key), signer)?;
+        let reply = ws_api.call(method, &params, settings.timeout).await?;
+
+        let status = StatusCode::from_u16(reply.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        settings.limiter.record(cost, status, &reply.headers(), api_key);
+
+        let reason = status.canonical_reason().unwrap_or("UNKNOWN");
+        let message = reply.error.map(|error| error.to_string()).unwrap_or_default();
+
+        if status.is_success() {
+            Ok(reply.result)
+        } else if status.is_server_error() {
+            let err = ServerError::new(status.as_u16(), reason, &message);
+
+            match (idempotency, client_order_id) {
+                (Idempotency::NewOrder, Some(id)) => Err(UnknownExecutionError::new(id, err).into()),
+                _ => Err(err.into()),
+            }
+        } else {
+            Err(ClientError::new(status.as_u16(), reason, &message).into())
+        }
+    }
+
+    fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            _marker: PhantomData,
+            params: self.params.clone(),
+            builder: self.builder.try_clone()?,
+            security: self.security,
+            validation: self.validation.clone(),
+            settings: self.settings,
+            ws_api: self.ws_api,
+        })
+    }
+
+    /// Validate the parameters and apply the default recv_window.
+    fn prepare(&mut self) -> crate::error::Result<()> {
+        if let Some((rules, mode)) = self.validation.take() {
+            rules.validate(&mut self.params, mode)?;
+        }
+
+        if let Security::Signed(_) = self.security {
+            self.params.recv_window = self.params.recv_window.or(self.settings.recv_window);
+        }
+        if let Some(recv_window) = self.params.recv_window {
+            client::check_recv_window(recv_window)?;
+        }
+        Ok(())
+    }
+
+    fn builder(mut self, key: Option<&ApiKey>) -> crate::error::Result<RequestBuilder> {
+        self.prepare()?;
+
+        let builder = if let Some(key) = key {
+            self.builder.header("X-MBX-APIKEY", key.key())
         } else {
             self.builder
         };
-        
-        let params = if let Some(secret_key) = self.secret_key {
-            self.params.sign(secret_key)?
-        } else {
-            &self.params
+
+        let params = match (self.security, key.and_then(ApiKey::signer)) {
+            (Security::Signed(_), Some(signer)) => self.params.sign(signer)?,
+            _ => &self.params,
         };
         
-        let builder = builder.header("User-Agent", "tokio-binance");
+        let builder = builder.header("User-Agent", &self.settings.user_agent);
+
+        let builder = match self.settings.timeout {
+            Some(timeout) => builder.timeout(timeout),
+            None => builder,
+        };
 
         // Cloning will never panic since the client does not set a body
         let request = builder.try_clone().expect("Unsupported body").build()?;
@@ -126,7 +408,7 @@ impl<'a, 'b, T: TimeInForce> ParamBuilder<'a, 'b, T> {
 }
 
 impl<'a, 'b, T: Price> ParamBuilder<'a, 'b, T> {
-    pub fn with_price(mut self, price: f64) -> Self {
+    pub fn with_price(mut self, price: Number) -> Self {
         self.params.price = Some(price);
         self
     }
@@ -134,19 +416,19 @@ impl<'a, 'b, T: Price> ParamBuilder<'a, 'b, T> {
 
 impl<'a, 'b, T: NewClientOrderId> ParamBuilder<'a, 'b, T> {
     pub fn with_new_client_order_id(mut self, new_client_order_id: &'a str) -> Self {
-        self.params.new_client_order_id = Some(new_client_order_id);
+        self.params.new_client_order_id = Some(new_client_order_id.into());
         self
     }
 }
 
 impl<'a, 'b, T: MarketOrderStopPrice> ParamBuilder<'a, 'b, T> {
-    pub fn with_stop_loss(mut self, stop_price: f64) -> Self {
+    pub fn with_stop_loss(mut self, stop_price: Number) -> Self {
         self.params.order_type = Some(param::OrderType::StopLoss);
         self.params.stop_price = Some(stop_price);
         self
     }
 
-    pub fn with_take_profit(mut self, stop_price: f64) -> Self {
+    pub fn with_take_profit(mut self, stop_price: Number) -> Self {
         self.params.order_type = Some(param::OrderType::TakeProfit);
         self.params.stop_price = Some(stop_price);
         self
@@ -154,13 +436,13 @@ impl<'a, 'b, T: MarketOrderStopPrice> ParamBuilder<'a, 'b, T> {
 }
 
 impl<'a, 'b, T: LimitOrderStopPrice> ParamBuilder<'a, 'b, T> {
-    pub fn with_stop_loss_limit(mut self, stop_price: f64) -> Self {
+    pub fn with_stop_loss_limit(mut self, stop_price: Number) -> Self {
         self.params.order_type = Some(param::OrderType::StopLossLimit);
         self.params.stop_price = Some(stop_price);
         self
     }
 
-    pub fn with_take_profit_limit(mut self, stop_price: f64) -> Self {
+    pub fn with_take_profit_limit(mut self, stop_price: Number) -> Self {
         self.params.order_type = Some(param::OrderType::TakeProfitLimit);
         self.params.stop_price = Some(stop_price);
         self
@@ -169,7 +451,7 @@ impl<'a, 'b, T: LimitOrderStopPrice> ParamBuilder<'a, 'b, T> {
 
 impl<'a, 'b, T: LimitMaker> ParamBuilder<'a, 'b, T> {
     pub fn into_limit_maker_order(self) -> ParamBuilder<'a, 'b, LimitMakerOrderParams> {
-        ParamBuilder::new(
+        let mut builder = ParamBuilder::new(
             Parameters { 
                 symbol: self.params.symbol,
                 side: self.params.side,
@@ -179,14 +461,17 @@ impl<'a, 'b, T: LimitMaker> ParamBuilder<'a, 'b, T> {
                 ..Parameters::default() 
             },
             self.builder,
-            self.api_key,
-            self.secret_key
-        )
+            self.security,
+            self.settings
+        );
+        builder.validation = self.validation;
+        builder.ws_api = self.ws_api;
+        builder
     }
 }
 
 impl<'a, 'b, T: IcebergQty> ParamBuilder<'a, 'b, T> {
-    pub fn with_iceberg_qty(mut self, iceberg_qty: f64) -> Self {
+    pub fn with_iceberg_qty(mut self, iceberg_qty: Number) -> Self {
         self.params.time_in_force = Some(param::TimeInForce::Gtc);
         self.params.iceberg_qty = Some(iceberg_qty);
         self
@@ -209,7 +494,7 @@ impl<'a, 'b, T: OrderId> ParamBuilder<'a, 'b, T> {
 
 impl<'a, 'b, T: ListClientOrderId> ParamBuilder<'a, 'b, T> {
     pub fn with_list_client_order_id(mut self, list_client_order_id: &'a str) -> Self {
-        self.params.list_client_order_id = Some(list_client_order_id);
+        self.params.list_client_order_id = Some(list_client_order_id.into());
         self
     }
 }
@@ -229,21 +514,21 @@ impl<'a, 'b, T: StopClientOrderId> ParamBuilder<'a, 'b, T> {
 }
 
 impl<'a, 'b, T: LimitIcebergQty> ParamBuilder<'a, 'b, T> {
-    pub fn with_limit_iceberg_qty(mut self, limit_iceberg_qty: f64) -> Self {
+    pub fn with_limit_iceberg_qty(mut self, limit_iceberg_qty: Number) -> Self {
         self.params.limit_iceberg_qty = Some(limit_iceberg_qty);
         self
     }
 }
 
 impl<'a, 'b, T: StopIcebergQty> ParamBuilder<'a, 'b, T> {
-    pub fn with_stop_iceberg_qty(mut self, stop_iceberg_qty: f64) -> Self {
+    pub fn with_stop_iceberg_qty(mut self, stop_iceberg_qty: Number) -> Self {
         self.params.stop_iceberg_qty = Some(stop_iceberg_qty);
         self
     }
 }
 
 impl<'a, 'b, T: StopLimitPrice> ParamBuilder<'a, 'b, T> {
-    pub fn with_stop_limit_price(mut self, stop_limit_price: f64, time_in_force: param::TimeInForce) -> Self {
+    pub fn with_stop_limit_price(mut self, stop_limit_price: Number, time_in_force: param::TimeInForce) -> Self {
         self.params.stop_limit_time_in_force = Some(time_in_force);
         self.params.stop_limit_price = Some(stop_limit_price);
         self
@@ -297,4 +582,885 @@ impl<'a, 'b, T: RecvWindow> ParamBuilder<'a, 'b, T> {
         self.params.recv_window = Some(recv_window);
         self
     }
-}
\ No newline at end of file
+}
+
+impl<'a, 'b, T: Validation> ParamBuilder<'a, 'b, T> {
+    /// Check the order against the trading rules of its symbol before sending it.
+    pub fn with_validation(mut self, rules: &ExchangeRules, mode: ValidationMode) -> Self {
+        self.validation = Some((rules.clone(), mode));
+        self
+    }
+}
+
+impl<'a, 'b, T: PositionSide> ParamBuilder<'a, 'b, T> {
+    /// Position to open or close in hedge mode; default is `Both` for one-way mode.
+    pub fn with_position_side(mut self, position_side: param::PositionSide) -> Self {
+        self.params.position_side = Some(position_side);
+        self
+    }
+}
+
+impl<'a, 'b, T: ReduceOnly> ParamBuilder<'a, 'b, T> {
+    /// Only reduce the position; can't be used in hedge mode.
+    pub fn with_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.params.reduce_only = Some(reduce_only);
+        self
+    }
+}
+
+impl<'a, 'b, T: ClosePosition> ParamBuilder<'a, 'b, T> {
+    /// Close the whole position when triggered, instead of a quantity;
+    /// requires `with_stop_price` or `with_take_profit_price`.
+    pub fn with_close_position(mut self) -> Self {
+        self.params.close_position = Some(true);
+        self.params.quantity = None;
+        self.params.reduce_only = None;
+        self
+    }
+}
+
+impl<'a, 'b, T: WorkingType> ParamBuilder<'a, 'b, T> {
+    /// Price compared against the stop price; default is `ContractPrice`.
+    pub fn with_working_type(mut self, working_type: param::WorkingType) -> Self {
+        self.params.working_type = Some(working_type);
+        self
+    }
+}
+
+impl<'a, 'b, T: FuturesStopPrice> ParamBuilder<'a, 'b, T> {
+    /// Converts Limit to Stop and Market to Stop-Market; triggers when the price moves past `stop_price`.
+    /// Replaces an earlier `with_take_profit_price`.
+    pub fn with_stop_price(mut self, stop_price: Number) -> Self {
+        self.params.order_type = Some(if self.is_limit() {
+            param::OrderType::Stop
+        } else {
+            param::OrderType::StopMarket
+        });
+        self.params.stop_price = Some(stop_price);
+        self
+    }
+    /// Converts Limit to Take-Profit and Market to Take-Profit-Market; triggers when the price moves past `stop_price`.
+    /// Replaces an earlier `with_stop_price`.
+    pub fn with_take_profit_price(mut self, stop_price: Number) -> Self {
+        self.params.order_type = Some(if self.is_limit() {
+            param::OrderType::TakeProfit
+        } else {
+            param::OrderType::TakeProfitMarket
+        });
+        self.params.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Whether the order has a limit price, including after a stop or take profit price was set.
+    fn is_limit(&self) -> bool {
+        matches!(
+            self.params.order_type,
+            Some(param::OrderType::Limit) | Some(param::OrderType::Stop) | Some(param::OrderType::TakeProfit)
+        )
+    }
+}
+
+impl<'a, 'b, T: IncomeType> ParamBuilder<'a, 'b, T> {
+    pub fn with_income_type(mut self, income_type: param::IncomeType) -> Self {
+        self.params.income_type = Some(income_type);
+        self
+    }
+}
+
+impl<'a, 'b, T: Pair> ParamBuilder<'a, 'b, T> {
+    pub fn with_pair(mut self, pair: &'a str) -> Self {
+        self.params.pair = Some(pair);
+        self
+    }
+}
+
+impl<'a, 'b, T: MarginAsset> ParamBuilder<'a, 'b, T> {
+    pub fn with_margin_asset(mut self, margin_asset: &'a str) -> Self {
+        self.params.margin_asset = Some(margin_asset);
+        self
+    }
+}
+
+impl<'a, 'b, T: Isolated> ParamBuilder<'a, 'b, T> {
+    /// Trade on the isolated margin account of the symbol; default is the cross margin account.
+    pub fn with_isolated(mut self, is_isolated: bool) -> Self {
+        self.params.is_isolated = Some(is_isolated);
+        self
+    }
+}
+
+impl<'a, 'b, T: IsolatedSymbol> ParamBuilder<'a, 'b, T> {
+    /// Query the isolated margin account of the symbol; default is the cross margin account.
+    pub fn with_isolated_symbol(mut self, isolated_symbol: &'a str) -> Self {
+        self.params.isolated_symbol = Some(isolated_symbol);
+        self
+    }
+}
+
+impl<'a, 'b, T: SideEffectType> ParamBuilder<'a, 'b, T> {
+    /// Borrow or repay along with the order; default is `NoSideEffect`.
+    pub fn with_side_effect_type(mut self, side_effect_type: param::SideEffectType) -> Self {
+        self.params.side_effect_type = Some(side_effect_type);
+        self
+    }
+}
+
+impl<'a, 'b, T: Current> ParamBuilder<'a, 'b, T> {
+    /// Page of the results, starting at 1.
+    pub fn with_current(mut self, current: usize) -> Self {
+        self.params.current = Some(current);
+        self
+    }
+}
+
+impl<'a, 'b, T: Size> ParamBuilder<'a, 'b, T> {
+    /// Results per page; default 10, max 100.
+    pub fn with_size(mut self, size: usize) -> Self {
+        self.params.size = Some(size);
+        self
+    }
+}
+
+impl<'a, 'b, T: Coin> ParamBuilder<'a, 'b, T> {
+    pub fn with_coin(mut self, coin: &'a str) -> Self {
+        self.params.coin = Some(coin);
+        self
+    }
+}
+
+impl<'a, 'b, T: Network> ParamBuilder<'a, 'b, T> {
+    /// Network to transfer on, e.g. `BSC` or `ETH`; default is the coin's default network.
+    pub fn with_network(mut self, network: &'a str) -> Self {
+        self.params.network = Some(network);
+        self
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, DepositHistoryParams> {
+    /// Same as `with_coin`, which the capital endpoints call the asset.
+    pub fn with_asset(self, asset: &'a str) -> Self {
+        self.with_coin(asset)
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, WithdrawHistoryParams> {
+    /// Same as `with_coin`, which the capital endpoints call the asset.
+    pub fn with_asset(self, asset: &'a str) -> Self {
+        self.with_coin(asset)
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, DepositAddressParams> {
+    /// Has no effect; the sapi endpoint always returns the address.
+    #[deprecated(note = "the deposit address has no status on the sapi endpoint")]
+    pub fn with_status<J: Into<serde_json::Value>>(self, _status: J) -> Self {
+        self
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, SubAccountParams> {
+    /// Only frozen sub-accounts if `true`, only active ones if `false`; default is both.
+    pub fn with_is_freeze(mut self, is_freeze: bool) -> Self {
+        self.params.is_freeze = Some(is_freeze);
+        self
+    }
+    /// Sub-account status `enabled` or `disabled`, sent as `with_is_freeze(false)` or `with_is_freeze(true)`.
+    #[deprecated(note = "use `with_is_freeze`")]
+    pub fn with_status<J: Into<serde_json::Value>>(mut self, status: J) -> Self {
+        self.params.is_freeze = match status.into().as_str() {
+            Some("enabled") => Some(false),
+            Some("disabled") => Some(true),
+            _ => None,
+        };
+        self
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, SubAccountAssetParams> {
+    /// Has no effect; the sapi endpoint returns every asset.
+    #[deprecated(note = "sub-account assets can't be filtered by symbol on the sapi endpoint")]
+    pub fn with_symbol(self, _symbol: &'a str) -> Self {
+        self
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, LoanParams> {
+    /// Borrow or repay on the isolated margin account of the symbol; default is the cross margin account.
+    pub fn with_isolated_account(mut self, symbol: &'a str) -> Self {
+        self.params.is_isolated = Some(true);
+        self.params.symbol = Some(symbol);
+        self
+    }
+    /// Sends the request and decodes the response as a [`MarginTransaction`].
+    pub async fn transaction(self) -> crate::error::Result<MarginTransaction> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, TimeParams> {
+    /// Sends the request and decodes the response as [`ServerTime`].
+    pub async fn server_time(self) -> crate::error::Result<ServerTime> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, ExchangeInfoParams> {
+    /// Sends the request and decodes the response as [`ExchangeInfo`].
+    pub async fn exchange_info(self) -> crate::error::Result<ExchangeInfo> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, OrderBookParams> {
+    /// Sends the request and decodes the response as an [`OrderBook`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarketDataClient, BINANCE_US_URL};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarketDataClient::connect("<api-key>", BINANCE_US_URL)?;
+    /// let book = client.get_order_book("BNBUSDT").with_limit(5).order_book().await?;
+    /// println!("best bid: {:?}", book.bids.first());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn order_book(self) -> crate::error::Result<OrderBook> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, TradesParams> {
+    /// Sends the request and decodes the response as a list of [`Trade`].
+    pub async fn trades(self) -> crate::error::Result<Vec<Trade>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, HistoricalTradesParams> {
+    /// Sends the request and decodes the response as a list of [`Trade`].
+    pub async fn trades(self) -> crate::error::Result<Vec<Trade>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, AggTradesParams> {
+    /// Sends the request and decodes the response as a list of [`AggTrade`].
+    pub async fn agg_trades(self) -> crate::error::Result<Vec<AggTrade>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, KlinesParams> {
+    /// Sends the request and decodes the response as a list of [`Kline`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarketDataClient, BINANCE_US_URL};
+    /// use tokio_binance::Interval;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarketDataClient::connect("<api-key>", BINANCE_US_URL)?;
+    /// let klines = client
+    ///     .get_candlestick_bars("BNBUSDT", Interval::OneMinute)
+    ///     .with_limit(100)
+    ///     .klines()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn klines(self) -> crate::error::Result<Vec<Kline>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, AveragePriceParams> {
+    /// Sends the request and decodes the response as an [`AvgPrice`].
+    pub async fn avg_price(self) -> crate::error::Result<AvgPrice> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, TwentyfourHourTickerPriceParams> {
+    /// Sends the request and decodes the response as a single [`Ticker24h`].
+    /// Requires `with_symbol`.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarketDataClient, BINANCE_US_URL};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarketDataClient::connect("<api-key>", BINANCE_US_URL)?;
+    /// let ticker = client
+    ///     .get_24hr_ticker_price()
+    ///     .with_symbol("BNBUSDT")
+    ///     .ticker()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ticker(self) -> crate::error::Result<Ticker24h> {
+        self.json().await
+    }
+    /// Sends the request and decodes the response as a list of [`Ticker24h`].
+    pub async fn tickers(self) -> crate::error::Result<Vec<Ticker24h>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, TickerPriceParams> {
+    /// Sends the request and decodes the response as a single [`PriceTicker`].
+    /// Requires `with_symbol`.
+    pub async fn ticker(self) -> crate::error::Result<PriceTicker> {
+        self.json().await
+    }
+    /// Sends the request and decodes the response as a list of [`PriceTicker`].
+    pub async fn tickers(self) -> crate::error::Result<Vec<PriceTicker>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, OrderBookTickerParams> {
+    /// Sends the request and decodes the response as a single [`BookTicker`].
+    /// Requires `with_symbol`.
+    pub async fn ticker(self) -> crate::error::Result<BookTicker> {
+        self.json().await
+    }
+    /// Sends the request and decodes the response as a list of [`BookTicker`].
+    pub async fn tickers(self) -> crate::error::Result<Vec<BookTicker>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, LimitOrderParams> {
+    /// Sends the order with `OrderRespType::Ack` and decodes the response as an [`OrderAck`].
+    pub async fn ack(mut self) -> crate::error::Result<OrderAck> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Ack);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Result` and decodes the response as an [`OrderResult`].
+    pub async fn result(mut self) -> crate::error::Result<OrderResult> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Result);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Full` and decodes the response as an [`OrderFull`].
+    /// Never looked up after a retry, since looked up orders have no fills; fails with
+    /// an unknown execution status instead.
+    /// Test orders return an empty object and can't be decoded into any of these types.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{AccountClient, BINANCE_US_URL};
+    /// use tokio_binance::Side::Buy;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+    /// let order = client
+    ///     .place_limit_order("BNBUSDT", Buy, "20.00".parse()?, "5.00".parse()?, true)
+    ///     .full()
+    ///     .await?;
+    ///
+    /// for fill in order.fills {
+    ///     println!("{} @ {}", fill.qty, fill.price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn full(mut self) -> crate::error::Result<OrderFull> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Full);
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, LimitMakerOrderParams> {
+    /// Sends the order with `OrderRespType::Ack` and decodes the response as an [`OrderAck`].
+    pub async fn ack(mut self) -> crate::error::Result<OrderAck> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Ack);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Result` and decodes the response as an [`OrderResult`].
+    pub async fn result(mut self) -> crate::error::Result<OrderResult> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Result);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Full` and decodes the response as an [`OrderFull`].
+    /// Never looked up after a retry, since looked up orders have no fills; fails with
+    /// an unknown execution status instead.
+    /// Test orders return an empty object and can't be decoded into any of these types.
+    pub async fn full(mut self) -> crate::error::Result<OrderFull> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Full);
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, MarketOrderParams> {
+    /// Sends the order with `OrderRespType::Ack` and decodes the response as an [`OrderAck`].
+    pub async fn ack(mut self) -> crate::error::Result<OrderAck> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Ack);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Result` and decodes the response as an [`OrderResult`].
+    pub async fn result(mut self) -> crate::error::Result<OrderResult> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Result);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Full` and decodes the response as an [`OrderFull`].
+    /// Never looked up after a retry, since looked up orders have no fills; fails with
+    /// an unknown execution status instead.
+    /// Test orders return an empty object and can't be decoded into any of these types.
+    pub async fn full(mut self) -> crate::error::Result<OrderFull> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Full);
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, OrderStatusParams> {
+    /// Sends the request and decodes the response as an [`Order`].
+    pub async fn order(self) -> crate::error::Result<Order> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, CancelOrderParams> {
+    /// Sends the request and decodes the response as a [`CanceledOrder`].
+    pub async fn canceled_order(self) -> crate::error::Result<CanceledOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, OpenOrderParams> {
+    /// Sends the request and decodes the response as a list of [`Order`].
+    pub async fn orders(self) -> crate::error::Result<Vec<Order>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, AllOrdersParams> {
+    /// Sends the request and decodes the response as a list of [`Order`].
+    pub async fn orders(self) -> crate::error::Result<Vec<Order>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, AccountParams> {
+    /// Sends the request and decodes the response as an [`AccountInfo`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{AccountClient, BINANCE_US_URL};
+    /// use tokio_binance::Number;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+    /// let account = client.get_account().account().await?;
+    ///
+    /// for balance in account.balances.iter().filter(|b| b.free > Number::default()) {
+    ///     println!("{}: {}", balance.asset, balance.free);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn account(self) -> crate::error::Result<AccountInfo> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, AccountTradesParams> {
+    /// Sends the request and decodes the response as a list of [`MyTrade`].
+    pub async fn trades(self) -> crate::error::Result<Vec<MyTrade>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, StartStreamParams> {
+    /// Sends the request and decodes the response as a [`ListenKey`].
+    pub async fn listen_key(self) -> crate::error::Result<ListenKey> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, FuturesLimitOrderParams> {
+    /// Sends the order and decodes the response as a [`FuturesOrder`].
+    /// Test orders return an empty object and can't be decoded.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    /// use tokio_binance::{Side::Sell, WorkingType::MarkPrice};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let order = client
+    ///     .place_limit_order("BTCUSDT", Sell, "60000.0".parse()?, "0.01".parse()?, true)
+    ///     .with_stop_price("61000.0".parse()?)
+    ///     .with_working_type(MarkPrice)
+    ///     .order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn order(self) -> crate::error::Result<FuturesOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, FuturesMarketOrderParams> {
+    /// Sends the order and decodes the response as a [`FuturesOrder`].
+    /// Test orders return an empty object and can't be decoded.
+    pub async fn order(self) -> crate::error::Result<FuturesOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, FuturesOrderStatusParams> {
+    /// Sends the request and decodes the response as a [`FuturesOrder`].
+    pub async fn order(self) -> crate::error::Result<FuturesOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, FuturesCancelOrderParams> {
+    /// Sends the request and decodes the response as a [`FuturesOrder`].
+    pub async fn canceled_order(self) -> crate::error::Result<FuturesOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, PositionRiskParams> {
+    /// Sends the request and decodes the response as a list of [`PositionRisk`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// for position in client.get_position_risk().positions().await? {
+    ///     println!("{}: {} @ {}", position.symbol, position.position_amt, position.entry_price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn positions(self) -> crate::error::Result<Vec<PositionRisk>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, LeverageParams> {
+    /// Sends the request and decodes the response as a [`Leverage`].
+    pub async fn leverage(self) -> crate::error::Result<Leverage> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, FuturesBalanceParams> {
+    /// Sends the request and decodes the response as a list of [`FuturesBalance`].
+    pub async fn balances(self) -> crate::error::Result<Vec<FuturesBalance>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, IncomeParams> {
+    /// Sends the request and decodes the response as a list of [`Income`].
+    pub async fn income(self) -> crate::error::Result<Vec<Income>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, FundingRateParams> {
+    /// Sends the request and decodes the response as a list of [`FundingRate`].
+    pub async fn funding_rates(self) -> crate::error::Result<Vec<FundingRate>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, DeliveryExchangeInfoParams> {
+    /// Sends the request and decodes the response as a [`DeliveryExchangeInfo`].
+    pub async fn exchange_info(self) -> crate::error::Result<DeliveryExchangeInfo> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, DeliveryLimitOrderParams> {
+    /// Sends the order and decodes the response as a [`DeliveryOrder`].
+    /// Test orders return an empty object and can't be decoded.
+    pub async fn order(self) -> crate::error::Result<DeliveryOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, DeliveryMarketOrderParams> {
+    /// Sends the order and decodes the response as a [`DeliveryOrder`].
+    /// Test orders return an empty object and can't be decoded.
+    pub async fn order(self) -> crate::error::Result<DeliveryOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, DeliveryOrderStatusParams> {
+    /// Sends the request and decodes the response as a [`DeliveryOrder`].
+    pub async fn order(self) -> crate::error::Result<DeliveryOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, DeliveryCancelOrderParams> {
+    /// Sends the request and decodes the response as a [`DeliveryOrder`].
+    pub async fn canceled_order(self) -> crate::error::Result<DeliveryOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, DeliveryPositionParams> {
+    /// Sends the request and decodes the response as a list of [`PositionRisk`].
+    pub async fn positions(self) -> crate::error::Result<Vec<PositionRisk>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, LeverageBracketParams> {
+    /// Sends the request and decodes the response as a list of [`LeverageBrackets`].
+    pub async fn brackets(self) -> crate::error::Result<Vec<LeverageBrackets>> {
+        self.json().await
+    }
+}
+
+/// Whether a request failed without being executed.
+fn refused(result: &crate::error::Result<Response>) -> bool {
+    match result {
+        Ok(_) => false,
+        Err(e) => {
+            let connect = e.source().and_then(|e| e.downcast_ref::<reqwest::Error>()).is_some_and(reqwest::Error::is_connect);
+            connect || e.is_rate_limited()
+        }
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, MarginLimitOrderParams> {
+    /// Sends the order with `OrderRespType::Ack` and decodes the response as an [`OrderAck`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use tokio_binance::Side::Sell;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let ack = client
+    ///     .place_limit_order("BNBUSDT", Sell, "30.00".parse()?, "5.00".parse()?)
+    ///     .ack()
+    ///     .await?;
+    /// println!("{} {}", ack.order_id, ack.client_order_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ack(mut self) -> crate::error::Result<OrderAck> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Ack);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Result` and decodes the response as an [`OrderResult`].
+    pub async fn result(mut self) -> crate::error::Result<OrderResult> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Result);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Full` and decodes the response as an [`OrderFull`].
+    /// Never looked up after a retry, since looked up orders have no fills; fails with
+    /// an unknown execution status instead.
+    pub async fn full(mut self) -> crate::error::Result<OrderFull> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Full);
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, MarginMarketOrderParams> {
+    /// Sends the order with `OrderRespType::Ack` and decodes the response as an [`OrderAck`].
+    pub async fn ack(mut self) -> crate::error::Result<OrderAck> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Ack);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Result` and decodes the response as an [`OrderResult`].
+    pub async fn result(mut self) -> crate::error::Result<OrderResult> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Result);
+        self.json().await
+    }
+    /// Sends the order with `OrderRespType::Full` and decodes the response as an [`OrderFull`].
+    /// Never looked up after a retry, since looked up orders have no fills; fails with
+    /// an unknown execution status instead.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use tokio_binance::{Side::Sell, SideEffectType::AutoRepay};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let order = client
+    ///     .place_market_order("BNBUSDT", Sell, "5.00".parse()?)
+    ///     .with_side_effect_type(AutoRepay)
+    ///     .full()
+    ///     .await?;
+    ///
+    /// for fill in order.fills {
+    ///     println!("{} at {}", fill.qty, fill.price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn full(mut self) -> crate::error::Result<OrderFull> {
+        self.params.new_order_resp_type = Some(param::OrderRespType::Full);
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, MarginOrderStatusParams> {
+    /// Sends the request and decodes the response as an [`Order`].
+    pub async fn order(self) -> crate::error::Result<Order> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, MarginCancelOrderParams> {
+    /// Sends the request and decodes the response as a [`CanceledOrder`].
+    pub async fn canceled_order(self) -> crate::error::Result<CanceledOrder> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, MarginAccountParams> {
+    /// Sends the request and decodes the response as a [`MarginAccount`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use tokio_binance::Number;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let account = client.get_account().account().await?;
+    ///
+    /// for asset in account.user_assets.iter().filter(|a| a.borrowed > Number::default()) {
+    ///     println!("{}: {} borrowed, {} interest", asset.asset, asset.borrowed, asset.interest);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn account(self) -> crate::error::Result<MarginAccount> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, IsolatedMarginAccountParams> {
+    /// Sends the request and decodes the response as an [`IsolatedMarginAccount`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let account = client.get_isolated_account().account().await?;
+    ///
+    /// for pair in account.assets {
+    ///     println!("{}: margin level {} ({})", pair.symbol, pair.margin_level, pair.margin_level_status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn account(self) -> crate::error::Result<IsolatedMarginAccount> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, MaxBorrowableParams> {
+    /// Sends the request and decodes the response as a [`MaxAmount`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let max = client.get_max_borrowable("USDT").amount().await?;
+    /// println!("{} of {:?}", max.amount, max.borrow_limit);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn amount(self) -> crate::error::Result<MaxAmount> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, MaxTransferableParams> {
+    /// Sends the request and decodes the response as a [`MaxAmount`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let max = client.get_max_transferable("USDT").amount().await?;
+    /// println!("{}", max.amount);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn amount(self) -> crate::error::Result<MaxAmount> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, InterestHistoryParams> {
+    /// Sends the request and decodes the response as a page of [`InterestEntry`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let page = client.get_interest_history().with_size(100).interest().await?;
+    ///
+    /// for entry in page.rows {
+    ///     println!("{} {}", entry.asset, entry.interest);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn interest(self) -> crate::error::Result<Rows<InterestEntry>> {
+        self.json().await
+    }
+}
+
+impl<'a, 'b> ParamBuilder<'a, 'b, ForceLiquidationParams> {
+    /// Sends the request and decodes the response as a page of [`LiquidationEntry`].
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let page = client.get_force_liquidation_history().liquidations().await?;
+    ///
+    /// for entry in page.rows {
+    ///     println!("{} {:?} {} at {}", entry.symbol, entry.side, entry.executed_qty, entry.avg_price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn liquidations(self) -> crate::error::Result<Rows<LiquidationEntry>> {
+        self.json().await
+    }
+}
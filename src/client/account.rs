@@ -1,5 +1,6 @@
 use reqwest::{Url, Client};
 use crate::param::{
+    Number,
     Parameters, 
     OrderType, 
     Side, 
@@ -7,18 +8,23 @@ use crate::param::{
     ID
 };
 use crate::builder::ParamBuilder;
+use crate::environment::RestUrl;
+use crate::credentials::{ApiKey, Credentials, Security};
+use crate::signer::Signer;
 use crate::types::*;
 use crate::client::*;
 
 /// Client for dealing with orders
 #[derive(Clone)]
 pub struct AccountClient {
-    api_key: String,
-    secret_key: String,
-    url: Url,
-    client: Client
+    pub(super) credentials: Credentials,
+    pub(super) url: Url,
+    pub(super) client: Client,
+    pub(super) settings: Settings,
 }
 
+settings_setters!(AccountClient, "use tokio_binance::{AccountClient, BINANCE_US_URL};", "AccountClient::connect(\"<api-key>\", \"<secret-key>\", BINANCE_US_URL)?", "BINANCE_US_URL", [rate_limiter, retry_policy, timeout, connect_timeout, recv_window, time_sync]);
+
 impl AccountClient {
     /// Creates new client instance.
     /// # Example
@@ -36,15 +42,78 @@ impl AccountClient {
     where
         A: Into<String>,
         S: Into<String>,
-        U: Into<String>
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials: Credentials::new(ApiKey::new(api_key, secret_key)),
+            url,
+            client,
+            settings
+        })
+    }
+    /// Creates new client instance signing requests with `signer`, e.g. for RSA or Ed25519 API keys.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{AccountClient, HmacSigner, BINANCE_US_URL};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let signer = HmacSigner::new("<secret-key>");
+    ///     let client = AccountClient::connect_with_signer("<api-key>", signer, BINANCE_US_URL)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_signer<A, S, U>(api_key: A, signer: S, url: U) -> crate::error::Result<Self> 
+    where
+        A: Into<String>,
+        S: Signer + 'static,
+        U: Into<RestUrl>
     {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
         Ok(Self {
-            api_key: api_key.into(), 
-            secret_key: secret_key.into(),
-            url: url.into().parse::<Url>()?,
-            client: Client::new()
+            credentials: Credentials::new(ApiKey::with_signer(api_key, signer)),
+            url,
+            client,
+            settings
         })
     }
+    /// Creates new client instance using `credentials`, which can be rotated without reconnecting.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{AccountClient, Credentials, EnvCredentials, BINANCE_US_URL};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let credentials = Credentials::from_provider(EnvCredentials::default())?;
+    ///     let client = AccountClient::connect_with_credentials(credentials, BINANCE_US_URL)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_credentials<U>(credentials: Credentials, url: U) -> crate::error::Result<Self> 
+    where
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials,
+            url,
+            client,
+            settings
+        })
+    }
+    /// Credentials of this client, shared with the clients converted from it.
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
     /// Place a new limit order.
     /// # Example
     ///
@@ -58,19 +127,19 @@ impl AccountClient {
     /// # let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
     /// let response = client
     ///     // false will send as test, true will send as a real order.
-    ///     .place_limit_order("BNBUSDT", Sell, 20.00, 5.00, false)
+    ///     .place_limit_order("BNBUSDT", Sell, "20.00".parse()?, "5.00".parse()?, false)
     ///     // optional: lifetime of order; default is Gtc.
     ///     .with_time_in_force(Fok)
     ///     // optional: unique id; auto generated by default.
     ///     .with_new_client_order_id("<uuid>")
     ///     // optional: splits quantity; sets time in force to Gtc.
-    ///     .with_iceberg_qty(1.00)
+    ///     .with_iceberg_qty("1.00".parse()?)
     ///     // optional: output verbosity; default is Ack.
     ///     .with_new_order_resp_type(Full)
     ///     // optional: converts Limit to Stop-Limit; triggers when price hits below 21.00.
-    ///     .with_stop_loss_limit(21.00)
+    ///     .with_stop_loss_limit("21.00".parse()?)
     ///     // optional: converts Limit to Stop-Limit; triggers when price hits above 21.00.
-    ///     .with_take_profit_limit(21.00)
+    ///     .with_take_profit_limit("21.00".parse()?)
     ///     // optional: processing time for request; default is 5000, can't be above 60000.
     ///     .with_recv_window(8000)
     ///     // optional: converts Limit to Limit-Maker; consumes builder and returns a different one.
@@ -84,11 +153,11 @@ impl AccountClient {
     pub fn place_limit_order<'a>(
         &self, symbol: &'a str, 
         side: Side, 
-        price: f64, 
-        quantity: f64, 
+        price: Number, 
+        quantity: Number, 
         execute: bool
     ) -> ParamBuilder<'a, '_, LimitOrderParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = if execute {
             url.join("/api/v3/order").unwrap()
@@ -107,8 +176,8 @@ impl AccountClient {
                 ..Parameters::default() 
             },
             client.post(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Place a new market order.
@@ -124,15 +193,15 @@ impl AccountClient {
     /// # let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
     /// let response = client
     ///     // false will send as test, true will send as a real order.
-    ///     .place_market_order("BNBUSDT", Sell, 5.00, false)
+    ///     .place_market_order("BNBUSDT", Sell, "5.00".parse()?, false)
     ///     // optional: unique id; auto generated by default.
     ///     .with_new_client_order_id("<uuid>")
     ///     // optional: output verbosity; default is Ack.
     ///     .with_new_order_resp_type(Full)
     ///     // optional: converts Market to Stop-Loss; triggers when price hits below 21.00.
-    ///     .with_stop_loss(21.00)
+    ///     .with_stop_loss("21.00".parse()?)
     ///     // optional: converts Market to Stop-Loss; triggers when price hits above 21.00.
-    ///     .with_take_profit(21.00)
+    ///     .with_take_profit("21.00".parse()?)
     ///     // optional: processing time for request; default is 5000, can't be above 60000.
     ///     .with_recv_window(8000)
     ///     //
@@ -144,10 +213,10 @@ impl AccountClient {
     pub fn place_market_order<'a>(
         &self, symbol: &'a str, 
         side: Side, 
-        quantity: f64, 
+        quantity: Number, 
         execute: bool
     ) -> ParamBuilder<'a, '_, MarketOrderParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = if execute {
             url.join("/api/v3/order").unwrap()
@@ -164,8 +233,8 @@ impl AccountClient {
                 ..Parameters::default() 
             },
             client.post(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Get order.
@@ -190,7 +259,7 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn get_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, OrderStatusParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/order").unwrap();
 
@@ -214,8 +283,8 @@ impl AccountClient {
                 ..Parameters::default() 
             },
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Cancel order.
@@ -242,7 +311,7 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn cancel_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, CancelOrderParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/order").unwrap();
 
@@ -266,8 +335,8 @@ impl AccountClient {
                 ..Parameters::default() 
             },
             client.delete(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Get open orders.
@@ -293,15 +362,15 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn get_open_orders(&self) -> ParamBuilder<'_, '_, OpenOrderParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/openOrders").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Get all orders.
@@ -338,15 +407,15 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn get_all_orders<'a>(&self, symbol: &'a str) -> ParamBuilder<'a, '_, AllOrdersParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/allOrders").unwrap();
 
         ParamBuilder::new(
             Parameters { symbol: Some(symbol), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Place a new oco order.
@@ -365,19 +434,19 @@ impl AccountClient {
     /// # let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
     /// let response = client
     ///     // Limit to sell at 30.00 and Stop-Loss at 20.00; One cancels the other.
-    ///     .place_oco_order("BNBUSDT", Sell, 30.00, 20.00, 5.00)
+    ///     .place_oco_order("BNBUSDT", Sell, "30.00".parse()?, "20.00".parse()?, "5.00".parse()?)
     ///     // optional: A unique Id for the entire orderList; auto generated by default.
     ///     .with_list_client_order_id("<uuid>")
     ///     // optional: A unique Id for the limit order; auto generated by default.
     ///     .with_limit_client_order_id("<uuid>")
     ///     // optional: splits quantity for the limit order leg;
-    ///     .with_limit_iceberg_qty(1.00)
+    ///     .with_limit_iceberg_qty("1.00".parse()?)
     ///     // optional: A unique Id for the stop loss/stop loss limit leg; auto generated by default.
     ///     .with_stop_client_order_id("<uuid>")
     ///     // optional: Converts Stop-Loss to Stop-Limit; triggers bellow 20.00.
-    ///     .with_stop_limit_price(19.00, Gtc)
+    ///     .with_stop_limit_price("19.00".parse()?, Gtc)
     ///     // optional: splits quantity for the stop order leg;
-    ///     .with_stop_iceberg_qty(1.00)
+    ///     .with_stop_iceberg_qty("1.00".parse()?)
     ///     // optional: output verbosity; default is Ack.
     ///     .with_new_order_resp_type(Full)
     ///     // optional: processing time for request; default is 5000, can't be above 60000.
@@ -391,11 +460,11 @@ impl AccountClient {
     pub fn place_oco_order<'a>(
         &self, symbol: &'a str, 
         side: Side, 
-        price: f64,
-        stop_price: f64,
-        quantity: f64,
+        price: Number,
+        stop_price: Number,
+        quantity: Number,
     ) -> ParamBuilder<'a, '_, OcoParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/order/oco").unwrap();
 
@@ -409,8 +478,8 @@ impl AccountClient {
                 ..Parameters::default() 
             },
             client.post(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Cancel oco order.
@@ -437,7 +506,7 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn cancel_oco_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, CancelOcoParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/orderList").unwrap();
 
@@ -448,7 +517,7 @@ impl AccountClient {
         };
 
         let list_client_order_id = if let ID::ClientOId(id) = id {
-            Some(id)
+            Some(id.into())
         } else {
             None
         };
@@ -461,8 +530,8 @@ impl AccountClient {
                 ..Parameters::default() 
             },
             client.delete(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Get oco order.
@@ -487,7 +556,7 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn get_oco_order<'a>(&self, id: ID<'a>) -> ParamBuilder<'a, '_, OcoStatusParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/orderList").unwrap();
 
@@ -510,8 +579,8 @@ impl AccountClient {
                 ..Parameters::default() 
             },
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Get all oco orders.
@@ -548,15 +617,15 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn get_all_oco_orders(&self) -> ParamBuilder<'_, '_, AllOcoParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/allOrderList").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Get open oco orders.
@@ -580,15 +649,15 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn get_open_oco_orders(&self) -> ParamBuilder<'_, '_, OpenOcoParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/openOrderList").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Get current account information.
@@ -612,15 +681,15 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn get_account(&self) -> ParamBuilder<'_, '_, AccountParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/account").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Get trades for a specific account and symbol.
@@ -657,37 +726,51 @@ impl AccountClient {
     /// # }
     /// ```
     pub fn get_account_trades<'a>(&self, symbol: &'a str) -> ParamBuilder<'a, '_, AccountTradesParams>{
-        let Self { ref api_key, ref secret_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
 
         let url = url.join("/api/v3/myTrades").unwrap();
 
         ParamBuilder::new(
             Parameters { symbol: Some(symbol), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Helper method for getting a withdraw client instance.
     pub fn to_withdraw_client(&self) -> WithdrawalClient {
         WithdrawalClient { 
-            api_key: self.api_key.clone(),
-            secret_key: self.secret_key.clone(), 
+            credentials: self.credentials.clone(),
             url: self.url.clone(), 
-            client: self.client.clone() 
+            client: self.client.clone(),
+            settings: self.settings.clone()
+        }
+    }
+    /// Helper method for getting a margin client instance.
+    pub fn to_margin_client(&self) -> MarginClient {
+        MarginClient {
+            credentials: self.credentials.clone(),
+            url: self.url.clone(),
+            client: self.client.clone(),
+            settings: self.settings.clone()
         }
     }
     /// Helper method for getting a market client instance.
     pub fn to_market_data_client(&self) -> MarketDataClient {
         MarketDataClient { 
-            api_key: self.api_key.clone(), 
+            credentials: self.credentials.clone(),
             url: self.url.clone(), 
-            client: self.client.clone() 
+            client: self.client.clone(),
+            settings: self.settings.clone()
         }
     }
     /// Helper method for getting a general client instance.
     pub fn to_general_client(&self) -> GeneralClient {
-        GeneralClient { url: self.url.clone(), client: self.client.clone() }
+        GeneralClient { 
+            url: self.url.clone(), 
+            client: self.client.clone(),
+            settings: self.settings.clone()
+        }
     }
 
 }
\ No newline at end of file
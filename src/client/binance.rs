@@ -0,0 +1,219 @@
+use std::time::Duration;
+use reqwest::{Client, Proxy, Url};
+use crate::credentials::Credentials;
+use crate::environment::RestUrl;
+use crate::error::{Error, Kind};
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::time_sync::TimeSync;
+use crate::client::*;
+
+/// Entry point handing out clients that share one connection pool and configuration.
+/// # Example
+///
+/// ```no_run
+/// use tokio_binance::{BinanceClient, Credentials, ApiKey, BINANCE_US_URL};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let binance = BinanceClient::builder(BINANCE_US_URL)
+///         .with_credentials(Credentials::new(ApiKey::new("<api-key>", "<secret-key>")))
+///         .with_timeout(Duration::from_secs(10))
+///         .with_recv_window(8000)
+///         .build()?;
+///
+///     let market = binance.to_market_data_client()?;
+///     let account = binance.to_account_client()?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BinanceClient {
+    url: Url,
+    credentials: Option<Credentials>,
+    settings: Settings,
+}
+
+impl BinanceClient {
+    /// Configure a client for the API at `url`.
+    pub fn builder<U: Into<RestUrl>>(url: U) -> BinanceClientBuilder {
+        BinanceClientBuilder {
+            url: url.into(),
+            credentials: None,
+            client: None,
+            proxy: None,
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            recv_window: None,
+            limiter: None,
+            retry: None,
+            time_sync: false,
+        }
+    }
+    /// Client for general exchange information.
+    pub fn to_general_client(&self) -> GeneralClient {
+        GeneralClient {
+            url: self.url.clone(),
+            client: self.settings.client.clone(),
+            settings: self.settings.clone()
+        }
+    }
+    /// Client for market data; fails without credentials.
+    pub fn to_market_data_client(&self) -> crate::error::Result<MarketDataClient> {
+        Ok(MarketDataClient {
+            credentials: self.credentials()?,
+            url: self.url.clone(),
+            client: self.settings.client.clone(),
+            settings: self.settings.clone()
+        })
+    }
+    /// Client for dealing with orders; fails without credentials.
+    pub fn to_account_client(&self) -> crate::error::Result<AccountClient> {
+        Ok(AccountClient {
+            credentials: self.credentials()?,
+            url: self.url.clone(),
+            client: self.settings.client.clone(),
+            settings: self.settings.clone()
+        })
+    }
+    /// Client for margin trading; fails without credentials.
+    pub fn to_margin_client(&self) -> crate::error::Result<MarginClient> {
+        Ok(MarginClient {
+            credentials: self.credentials()?,
+            url: self.url.clone(),
+            client: self.settings.client.clone(),
+            settings: self.settings.clone()
+        })
+    }
+    /// Client for the user data stream; fails without credentials.
+    pub fn to_user_data_client(&self) -> crate::error::Result<UserDataClient> {
+        Ok(UserDataClient {
+            credentials: self.credentials()?,
+            url: self.url.clone(),
+            client: self.settings.client.clone(),
+            settings: self.settings.clone()
+        })
+    }
+    /// Client for withdrawals and sub accounts; fails without credentials.
+    pub fn to_withdraw_client(&self) -> crate::error::Result<WithdrawalClient> {
+        Ok(WithdrawalClient {
+            credentials: self.credentials()?,
+            url: self.url.clone(),
+            client: self.settings.client.clone(),
+            settings: self.settings.clone()
+        })
+    }
+
+    fn credentials(&self) -> crate::error::Result<Credentials> {
+        self.credentials.clone().ok_or_else(|| Error::new(Kind::Credentials, Some("no credentials configured")))
+    }
+}
+
+/// Configuration of a [`BinanceClient`].
+pub struct BinanceClientBuilder {
+    url: RestUrl,
+    credentials: Option<Credentials>,
+    client: Option<Client>,
+    proxy: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    recv_window: Option<usize>,
+    limiter: Option<RateLimiter>,
+    retry: Option<RetryPolicy>,
+    time_sync: bool,
+}
+
+impl BinanceClientBuilder {
+    /// Credentials of the clients that need an API key.
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+    /// Send requests with `client` instead of a new one.
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+    /// Send requests through the proxy at `url`; configure the proxy on the client
+    /// instead when using `with_client`.
+    pub fn with_proxy<P: Into<String>>(mut self, url: P) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+    /// Time until a request fails, from connecting until the response body is read.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    /// Time until connecting fails; configure it on the client instead when using `with_client`.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+    /// `User-Agent` header of requests; default is tokio-binance.
+    pub fn with_user_agent<A: Into<String>>(mut self, user_agent: A) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+    /// Processing time of signed requests that don't set one with `with_recv_window`;
+    /// can't be above 60000.
+    pub fn with_recv_window(mut self, recv_window: usize) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+    /// Use a rate limiter other than the one shared by all clients of the same host.
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+    /// Retry requests failing with a transient error.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+    /// Stamp signed requests with the server time, see [`TimeSync`].
+    pub fn with_time_sync(mut self, time_sync: bool) -> Self {
+        self.time_sync = time_sync;
+        self
+    }
+    /// Create the client; fails on an invalid URL, proxy or recv_window.
+    pub fn build(self) -> crate::error::Result<BinanceClient> {
+        let proxy = self.proxy.as_deref().map(Proxy::all).transpose()?;
+
+        if let Some(recv_window) = self.recv_window {
+            check_recv_window(recv_window)?;
+        }
+
+        let client = match self.client {
+            Some(_) if proxy.is_some() || self.connect_timeout.is_some() => {
+                let message = "a proxy or connect timeout can't be added to a custom client";
+                return Err(Error::new(Kind::Config, Some(message)));
+            }
+            Some(ref client) => client.clone(),
+            None => http_client(proxy.clone(), self.connect_timeout)?,
+        };
+
+        let (url, mut settings) = Settings::new(self.url, &client)?;
+        settings.limiter = self.limiter.unwrap_or(settings.limiter);
+        settings.retry = self.retry;
+        settings.timeout = self.timeout;
+        settings.recv_window = self.recv_window;
+        settings.connect_timeout = self.connect_timeout;
+        settings.proxy = proxy;
+        settings.custom_client = self.client.is_some();
+
+        if let Some(user_agent) = self.user_agent {
+            settings.user_agent = user_agent;
+        }
+
+        if self.time_sync {
+            let general = GeneralClient { url: url.clone(), client: client.clone(), settings: settings.clone() };
+            settings.time_sync = Some(TimeSync::new(general));
+        }
+
+        Ok(BinanceClient { url, credentials: self.credentials, settings })
+    }
+}
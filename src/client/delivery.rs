@@ -0,0 +1,542 @@
+use reqwest::{Url, Client};
+use crate::param::{
+    Number,
+    Parameters,
+    Interval,
+    OrderType,
+    Side,
+    TimeInForce,
+    ID
+};
+use crate::builder::ParamBuilder;
+use crate::environment::RestUrl;
+use crate::credentials::{ApiKey, Credentials, Security};
+use crate::signer::Signer;
+use crate::types::*;
+use crate::client::*;
+
+/// Client for dealing with COIN-M delivery and perpetual futures
+#[derive(Clone)]
+pub struct DeliveryClient {
+    pub(super) credentials: Credentials,
+    pub(super) url: Url,
+    pub(super) client: Client,
+    pub(super) settings: Settings,
+}
+
+settings_setters!(DeliveryClient, "use tokio_binance::{DeliveryClient, Environment};", "DeliveryClient::connect(\"<api-key>\", \"<secret-key>\", Environment::CoinMFutures)?", "Environment::Binance", [rate_limiter, retry_policy, timeout, connect_timeout, recv_window, time_sync]);
+
+impl DeliveryClient {
+    /// Creates new client instance.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{DeliveryClient, Environment};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect<A, S, U>(api_key: A, secret_key: S, url: U) -> crate::error::Result<Self>
+    where
+        A: Into<String>,
+        S: Into<String>,
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials: Credentials::new(ApiKey::new(api_key, secret_key)),
+            url,
+            client,
+            settings
+        })
+    }
+    /// Creates new client instance signing requests with `signer`, e.g. for RSA or Ed25519 API keys.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{DeliveryClient, HmacSigner, Environment};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let signer = HmacSigner::new("<secret-key>");
+    ///     let client = DeliveryClient::connect_with_signer("<api-key>", signer, Environment::CoinMFutures)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_signer<A, S, U>(api_key: A, signer: S, url: U) -> crate::error::Result<Self>
+    where
+        A: Into<String>,
+        S: Signer + 'static,
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials: Credentials::new(ApiKey::with_signer(api_key, signer)),
+            url,
+            client,
+            settings
+        })
+    }
+    /// Creates new client instance using `credentials`, which can be rotated without reconnecting.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{DeliveryClient, Credentials, EnvCredentials, Environment};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let credentials = Credentials::from_provider(EnvCredentials::default())?;
+    ///     let client = DeliveryClient::connect_with_credentials(credentials, Environment::CoinMFutures)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_credentials<U>(credentials: Credentials, url: U) -> crate::error::Result<Self>
+    where
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials,
+            url,
+            client,
+            settings
+        })
+    }
+    /// Credentials of this client.
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+    /// Current trading rules and contract information.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let info = client.get_exchange_info().exchange_info().await?;
+    ///
+    /// for symbol in info.symbols.iter().filter(|s| s.contract_type == "CURRENT_QUARTER") {
+    ///     println!("{}: {} {} per contract", symbol.symbol, symbol.contract_size, symbol.quote_asset);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_exchange_info(&self) -> ParamBuilder<'_, '_, DeliveryExchangeInfoParams>{
+        let Self { url, client, settings, .. } = self;
+
+        let url = url.join("/dapi/v1/exchangeInfo").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::None,
+            settings
+        )
+    }
+    /// Place a new limit order for a number of contracts.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    /// use tokio_binance::{Side::Buy, TimeInForce::Gtx, PositionSide::Long};
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let response = client
+    ///     // false will send as test, true will send as a real order.
+    ///     .place_limit_order("BTCUSD_PERP", Buy, "60000.0".parse()?, 10, false)
+    ///     // optional: lifetime of order; default is Gtc, Gtx is post-only.
+    ///     .with_time_in_force(Gtx)
+    ///     // optional: unique id; auto generated by default.
+    ///     .with_new_client_order_id("<uuid>")
+    ///     // optional: position in hedge mode; default is Both.
+    ///     .with_position_side(Long)
+    ///     // optional: converts Limit to Stop; triggers when the price hits 61000.0.
+    ///     .with_stop_price("61000.0".parse()?)
+    ///     // optional: processing time for request; default is 5000, can't be above 60000.
+    ///     .with_recv_window(8000)
+    ///     //
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn place_limit_order<'a>(
+        &self, symbol: &'a str,
+        side: Side,
+        price: Number,
+        contracts: u32,
+        execute: bool
+    ) -> ParamBuilder<'a, '_, DeliveryLimitOrderParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = if execute {
+            url.join("/dapi/v1/order").unwrap()
+        } else {
+            url.join("/dapi/v1/order/test").unwrap()
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                side: Some(side),
+                order_type: Some(OrderType::Limit),
+                price: Some(price),
+                quantity: Some(Number::from(contracts)),
+                time_in_force: Some(TimeInForce::Gtc),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Place a new market order for a number of contracts.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    /// use tokio_binance::Side::Sell;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let order = client
+    ///     .place_market_order("BTCUSD_PERP", Sell, 10, true)
+    ///     // optional: only reduce the position.
+    ///     .with_reduce_only(true)
+    ///     .order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn place_market_order<'a>(
+        &self, symbol: &'a str,
+        side: Side,
+        contracts: u32,
+        execute: bool
+    ) -> ParamBuilder<'a, '_, DeliveryMarketOrderParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = if execute {
+            url.join("/dapi/v1/order").unwrap()
+        } else {
+            url.join("/dapi/v1/order/test").unwrap()
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                side: Some(side),
+                order_type: Some(OrderType::Market),
+                quantity: Some(Number::from(contracts)),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Check an order's status.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    /// use tokio_binance::ID;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let order = client
+    ///     .get_order("BTCUSD_PERP", ID::ClientOId("<uuid>"))
+    ///     .order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, DeliveryOrderStatusParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/dapi/v1/order").unwrap();
+
+        let order_id = if let ID::OrderId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        let orig_client_order_id = if let ID::ClientOId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                order_id,
+                orig_client_order_id,
+                ..Parameters::default()
+            },
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Cancel an active order.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    /// use tokio_binance::ID;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let order = client
+    ///     .cancel_order("BTCUSD_PERP", ID::OrderId(1))
+    ///     .canceled_order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, DeliveryCancelOrderParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/dapi/v1/order").unwrap();
+
+        let order_id = if let ID::OrderId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        let orig_client_order_id = if let ID::ClientOId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                order_id,
+                orig_client_order_id,
+                ..Parameters::default()
+            },
+            client.delete(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Current positions; every contract unless filtered by pair or margin asset.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let positions = client
+    ///     .get_position_info()
+    ///     // optional: filter by pair.
+    ///     .with_pair("BTCUSD")
+    ///     // optional: filter by margin asset.
+    ///     .with_margin_asset("BTC")
+    ///     .positions()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_position_info(&self) -> ParamBuilder<'_, '_, DeliveryPositionParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/dapi/v1/positionRisk").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Leverage brackets; every contract unless `with_symbol` is set.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let brackets = client
+    ///     .get_leverage_brackets()
+    ///     .with_symbol("BTCUSD_PERP")
+    ///     .brackets()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_leverage_brackets(&self) -> ParamBuilder<'_, '_, LeverageBracketParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/dapi/v2/leverageBracket").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Kline/candlestick bars of the mark price of a contract.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    /// use tokio_binance::Interval;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let klines = client
+    ///     .get_mark_price_klines("BTCUSD_PERP", Interval::OneHour)
+    ///     // optional: limit the amount of bars; default 500, max 1500.
+    ///     .with_limit(24)
+    ///     .klines()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_mark_price_klines<'a>(&self, symbol: &'a str, interval: Interval) -> ParamBuilder<'a, '_, KlinesParams>{
+        let Self { url, client, settings, .. } = self;
+
+        let url = url.join("/dapi/v1/markPriceKlines").unwrap();
+
+        ParamBuilder::new(
+            Parameters { symbol: Some(symbol), interval: Some(interval), ..Parameters::default() },
+            client.get(url),
+            Security::None,
+            settings
+        )
+    }
+    /// Kline/candlestick bars of the index price of a pair.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    /// use tokio_binance::Interval;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let klines = client
+    ///     .get_index_price_klines("BTCUSD", Interval::OneHour)
+    ///     .klines()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_index_price_klines<'a>(&self, pair: &'a str, interval: Interval) -> ParamBuilder<'a, '_, KlinesParams>{
+        let Self { url, client, settings, .. } = self;
+
+        let url = url.join("/dapi/v1/indexPriceKlines").unwrap();
+
+        ParamBuilder::new(
+            Parameters { pair: Some(pair), interval: Some(interval), ..Parameters::default() },
+            client.get(url),
+            Security::None,
+            settings
+        )
+    }
+    /// Start a new COIN-M futures user data stream, or get the listen key of the active one.
+    /// The stream will close after 60 minutes unless a keepalive is sent.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// let listen_key = client
+    ///     .start_stream()
+    ///     .listen_key()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start_stream(&self) -> ParamBuilder<'_, '_, StartStreamParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/dapi/v1/listenKey").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.post(url),
+            Security::ApiKey(credentials),
+            settings
+        )
+    }
+    /// Keepalive the user data stream for another 60 minutes.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// client.keep_alive().text().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn keep_alive<'a>(&self) -> ParamBuilder<'a, '_, KeepAliveStreamParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/dapi/v1/listenKey").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.put(url),
+            Security::ApiKey(credentials),
+            settings
+        )
+    }
+    /// Close out the user data stream.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{DeliveryClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+    /// client.close_stream().text().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn close_stream<'a>(&self) -> ParamBuilder<'a, '_, CloseStreamParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/dapi/v1/listenKey").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.delete(url),
+            Security::ApiKey(credentials),
+            settings
+        )
+    }
+}
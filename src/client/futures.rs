@@ -0,0 +1,599 @@
+use reqwest::{Url, Client};
+use crate::param::{
+    Number,
+    Parameters,
+    MarginType,
+    OrderType,
+    Side,
+    TimeInForce,
+    ID
+};
+use crate::builder::ParamBuilder;
+use crate::environment::RestUrl;
+use crate::credentials::{ApiKey, Credentials, Security};
+use crate::signer::Signer;
+use crate::types::*;
+use crate::client::*;
+
+/// Client for dealing with USDⓈ-M futures orders and positions
+#[derive(Clone)]
+pub struct FuturesClient {
+    pub(super) credentials: Credentials,
+    pub(super) url: Url,
+    pub(super) client: Client,
+    pub(super) settings: Settings,
+}
+
+settings_setters!(FuturesClient, "use tokio_binance::{FuturesClient, Environment};", "FuturesClient::connect(\"<api-key>\", \"<secret-key>\", Environment::UsdMFutures)?", "Environment::Binance", [rate_limiter, retry_policy, timeout, connect_timeout, recv_window, time_sync]);
+
+impl FuturesClient {
+    /// Creates new client instance.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{FuturesClient, Environment};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect<A, S, U>(api_key: A, secret_key: S, url: U) -> crate::error::Result<Self>
+    where
+        A: Into<String>,
+        S: Into<String>,
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials: Credentials::new(ApiKey::new(api_key, secret_key)),
+            url,
+            client,
+            settings
+        })
+    }
+    /// Creates new client instance signing requests with `signer`, e.g. for RSA or Ed25519 API keys.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{FuturesClient, HmacSigner, Environment};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let signer = HmacSigner::new("<secret-key>");
+    ///     let client = FuturesClient::connect_with_signer("<api-key>", signer, Environment::UsdMFutures)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_signer<A, S, U>(api_key: A, signer: S, url: U) -> crate::error::Result<Self>
+    where
+        A: Into<String>,
+        S: Signer + 'static,
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials: Credentials::new(ApiKey::with_signer(api_key, signer)),
+            url,
+            client,
+            settings
+        })
+    }
+    /// Creates new client instance using `credentials`, which can be rotated without reconnecting.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{FuturesClient, Credentials, EnvCredentials, Environment};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let credentials = Credentials::from_provider(EnvCredentials::default())?;
+    ///     let client = FuturesClient::connect_with_credentials(credentials, Environment::UsdMFutures)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_credentials<U>(credentials: Credentials, url: U) -> crate::error::Result<Self>
+    where
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials,
+            url,
+            client,
+            settings
+        })
+    }
+    /// Credentials of this client.
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+    /// Place a new limit order.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    /// use tokio_binance::{Side::Buy, TimeInForce::Gtx, PositionSide::Long, WorkingType::MarkPrice};
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let response = client
+    ///     // false will send as test, true will send as a real order.
+    ///     .place_limit_order("BTCUSDT", Buy, "60000.0".parse()?, "0.01".parse()?, false)
+    ///     // optional: lifetime of order; default is Gtc, Gtx is post-only.
+    ///     .with_time_in_force(Gtx)
+    ///     // optional: unique id; auto generated by default.
+    ///     .with_new_client_order_id("<uuid>")
+    ///     // optional: position in hedge mode; default is Both.
+    ///     .with_position_side(Long)
+    ///     // optional: converts Limit to Stop; triggers when the price hits 61000.0.
+    ///     .with_stop_price("61000.0".parse()?)
+    ///     // optional: price compared against the stop price; default is ContractPrice.
+    ///     .with_working_type(MarkPrice)
+    ///     // optional: processing time for request; default is 5000, can't be above 60000.
+    ///     .with_recv_window(8000)
+    ///     //
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn place_limit_order<'a>(
+        &self, symbol: &'a str,
+        side: Side,
+        price: Number,
+        quantity: Number,
+        execute: bool
+    ) -> ParamBuilder<'a, '_, FuturesLimitOrderParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = if execute {
+            url.join("/fapi/v1/order").unwrap()
+        } else {
+            url.join("/fapi/v1/order/test").unwrap()
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                side: Some(side),
+                order_type: Some(OrderType::Limit),
+                price: Some(price),
+                quantity: Some(quantity),
+                time_in_force: Some(TimeInForce::Gtc),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Place a new market order.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    /// use tokio_binance::Side::Sell;
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let response = client
+    ///     // false will send as test, true will send as a real order.
+    ///     .place_market_order("BTCUSDT", Sell, "0.01".parse()?, false)
+    ///     // optional: only reduce the position.
+    ///     .with_reduce_only(true)
+    ///     // optional: converts Market to Take-Profit-Market; triggers when the price hits 58000.0.
+    ///     .with_take_profit_price("58000.0".parse()?)
+    ///     // optional: closes the whole position instead of the quantity when triggered.
+    ///     .with_close_position()
+    ///     //
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn place_market_order<'a>(
+        &self, symbol: &'a str,
+        side: Side,
+        quantity: Number,
+        execute: bool
+    ) -> ParamBuilder<'a, '_, FuturesMarketOrderParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = if execute {
+            url.join("/fapi/v1/order").unwrap()
+        } else {
+            url.join("/fapi/v1/order/test").unwrap()
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                side: Some(side),
+                order_type: Some(OrderType::Market),
+                quantity: Some(quantity),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Check an order's status.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    /// use tokio_binance::ID;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let order = client
+    ///     .get_order("BTCUSDT", ID::ClientOId("<uuid>"))
+    ///     .order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, FuturesOrderStatusParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/fapi/v1/order").unwrap();
+
+        let order_id = if let ID::OrderId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        let orig_client_order_id = if let ID::ClientOId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                order_id,
+                orig_client_order_id,
+                ..Parameters::default()
+            },
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Cancel an active order.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    /// use tokio_binance::ID;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let order = client
+    ///     .cancel_order("BTCUSDT", ID::OrderId(1))
+    ///     .canceled_order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, FuturesCancelOrderParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/fapi/v1/order").unwrap();
+
+        let order_id = if let ID::OrderId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        let orig_client_order_id = if let ID::ClientOId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                order_id,
+                orig_client_order_id,
+                ..Parameters::default()
+            },
+            client.delete(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Current positions; every symbol unless `with_symbol` is set.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let response = client
+    ///     .get_position_risk()
+    ///     // optional: filter by symbol.
+    ///     .with_symbol("BTCUSDT")
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_position_risk(&self) -> ParamBuilder<'_, '_, PositionRiskParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/fapi/v2/positionRisk").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Change the initial leverage of a symbol.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let leverage = client
+    ///     .change_leverage("BTCUSDT", 10)
+    ///     .leverage()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn change_leverage<'a>(&self, symbol: &'a str, leverage: u32) -> ParamBuilder<'a, '_, LeverageParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/fapi/v1/leverage").unwrap();
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                leverage: Some(leverage),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Change the margin type of a symbol; fails while it has open orders or a position.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    /// use tokio_binance::MarginType::Isolated;
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let response = client
+    ///     .change_margin_type("BTCUSDT", Isolated)
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn change_margin_type<'a>(&self, symbol: &'a str, margin_type: MarginType) -> ParamBuilder<'a, '_, MarginTypeParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/fapi/v1/marginType").unwrap();
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                margin_type: Some(margin_type),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Balance of every asset of the futures account.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// for balance in client.get_balance().balances().await? {
+    ///     println!("{}: {}", balance.asset, balance.available_balance);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_balance(&self) -> ParamBuilder<'_, '_, FuturesBalanceParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/fapi/v2/balance").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Realized profit, funding fees, commissions and transfers; the last 7 days by default.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    /// use tokio_binance::IncomeType::FundingFee;
+    /// use chrono::{Utc, Duration};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let end = Utc::now();
+    /// let start = end - Duration::days(1);
+    ///
+    /// let income = client
+    ///     .get_income_history()
+    ///     // optional: filter by symbol.
+    ///     .with_symbol("BTCUSDT")
+    ///     // optional: filter by kind.
+    ///     .with_income_type(FundingFee)
+    ///     // optional: get income from; default is 7 days ago.
+    ///     .with_start_time(start)
+    ///     // optional: get income until; default is now.
+    ///     .with_end_time(end)
+    ///     // optional: limit the amount of entries; default 100, max 1000.
+    ///     .with_limit(100)
+    ///     .income()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_income_history(&self) -> ParamBuilder<'_, '_, IncomeParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/fapi/v1/income").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Funding rate history; every symbol unless `with_symbol` is set.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let rates = client
+    ///     .get_funding_rate_history()
+    ///     .with_symbol("BTCUSDT")
+    ///     // optional: limit the amount of entries; default 100, max 1000.
+    ///     .with_limit(10)
+    ///     .funding_rates()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_funding_rate_history(&self) -> ParamBuilder<'_, '_, FundingRateParams>{
+        let Self { url, client, settings, .. } = self;
+
+        let url = url.join("/fapi/v1/fundingRate").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::None,
+            settings
+        )
+    }
+    /// Start a new USDⓈ-M futures user data stream, or get the listen key of the active one.
+    /// The stream will close after 60 minutes unless a keepalive is sent.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// let listen_key = client
+    ///     .start_stream()
+    ///     .listen_key()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start_stream(&self) -> ParamBuilder<'_, '_, StartStreamParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/fapi/v1/listenKey").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.post(url),
+            Security::ApiKey(credentials),
+            settings
+        )
+    }
+    /// Keepalive the user data stream for another 60 minutes.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// client.keep_alive().text().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn keep_alive<'a>(&self) -> ParamBuilder<'a, '_, KeepAliveStreamParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/fapi/v1/listenKey").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.put(url),
+            Security::ApiKey(credentials),
+            settings
+        )
+    }
+    /// Close out the user data stream.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{FuturesClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+    /// client.close_stream().text().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn close_stream<'a>(&self) -> ParamBuilder<'a, '_, CloseStreamParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/fapi/v1/listenKey").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.delete(url),
+            Security::ApiKey(credentials),
+            settings
+        )
+    }
+}
@@ -3,15 +3,21 @@ use crate::param::{
     Parameters, 
 };
 use crate::builder::ParamBuilder;
+use crate::environment::RestUrl;
+use crate::credentials::Security;
+use super::Settings;
 use crate::types::*;
 
 /// Client for dealing with general exchange information
 #[derive(Clone)]
 pub struct GeneralClient {
-    pub(super) url: Url,
-    pub(super) client: Client,
+    pub(crate) url: Url,
+    pub(crate) client: Client,
+    pub(super) settings: Settings,
 }
 
+settings_setters!(GeneralClient, "use tokio_binance::{GeneralClient, BINANCE_US_URL};", "GeneralClient::connect(BINANCE_US_URL)?", "BINANCE_US_URL", [rate_limiter, retry_policy, timeout, connect_timeout]);
+
 impl GeneralClient {
     /// Creates new client instance
     /// # Example
@@ -25,10 +31,14 @@ impl GeneralClient {
     ///     Ok(())
     /// }
     /// ```
-    pub fn connect<U: Into<String>>(url: U) -> crate::error::Result<Self> {
+    pub fn connect<U: Into<RestUrl>>(url: U) -> crate::error::Result<Self> {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
         Ok(Self {
-            url: url.into().parse::<Url>()?,
-            client: Client::new()
+            url,
+            client,
+            settings
         })
     }
     /// Test connectivity to the Rest API.
@@ -49,14 +59,14 @@ impl GeneralClient {
     /// # }
     /// ```
     pub fn ping(&self) -> ParamBuilder<'_, '_, PingParams>{
-        let Self { url, client } = self;
+        let Self { url, client, settings } = self;
         let url = url.join("/api/v3/ping").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            None,
-            None
+            Security::None,
+            settings
         )
     }
     /// Test connectivity to the Rest API and get the current server time.
@@ -77,14 +87,14 @@ impl GeneralClient {
     /// # }
     /// ```
     pub fn get_server_time(&self) -> ParamBuilder<'_, '_, TimeParams>{
-        let Self { url, client } = self;
+        let Self { url, client, settings } = self;
         let url = url.join("/api/v3/time").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            None,
-            None
+            Security::None,
+            settings
         )
     }
     /// Current exchange trading rules and symbol information.
@@ -105,14 +115,14 @@ impl GeneralClient {
     /// # }
     /// ```
     pub fn get_exchange_info(&self) -> ParamBuilder<'_, '_, ExchangeInfoParams>{
-        let Self { url, client } = self;
+        let Self { url, client, settings } = self;
         let url = url.join("/api/v3/exchangeInfo").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            None,
-            None
+            Security::None,
+            settings
         )
     }
 }
@@ -0,0 +1,651 @@
+use reqwest::{Url, Client};
+use crate::param::{
+    Number,
+    Parameters, 
+    OrderType, 
+    Side, 
+    TimeInForce,
+    LoanType,
+    ID
+};
+use crate::builder::ParamBuilder;
+use crate::environment::RestUrl;
+use crate::credentials::{ApiKey, Credentials, Security};
+use crate::signer::Signer;
+use crate::types::*;
+use crate::client::*;
+
+/// Client for cross and isolated margin trading
+#[derive(Clone)]
+pub struct MarginClient {
+    pub(super) credentials: Credentials,
+    pub(super) url: Url,
+    pub(super) client: Client,
+    pub(super) settings: Settings,
+}
+
+settings_setters!(MarginClient, "use tokio_binance::{MarginClient, Environment};", "MarginClient::connect(\"<api-key>\", \"<secret-key>\", Environment::Binance)?", "Environment::Binance", [rate_limiter, retry_policy, timeout, connect_timeout, recv_window, time_sync]);
+
+impl MarginClient {
+    /// Creates new client instance.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{MarginClient, Environment};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect<A, S, U>(api_key: A, secret_key: S, url: U) -> crate::error::Result<Self> 
+    where
+        A: Into<String>,
+        S: Into<String>,
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials: Credentials::new(ApiKey::new(api_key, secret_key)),
+            url,
+            client,
+            settings
+        })
+    }
+    /// Creates new client instance signing requests with `signer`, e.g. for RSA or Ed25519 API keys.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{MarginClient, HmacSigner, Environment};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let signer = HmacSigner::new("<secret-key>");
+    ///     let client = MarginClient::connect_with_signer("<api-key>", signer, Environment::Binance)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_signer<A, S, U>(api_key: A, signer: S, url: U) -> crate::error::Result<Self> 
+    where
+        A: Into<String>,
+        S: Signer + 'static,
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials: Credentials::new(ApiKey::with_signer(api_key, signer)),
+            url,
+            client,
+            settings
+        })
+    }
+    /// Creates new client instance using `credentials`, which can be rotated without reconnecting.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{MarginClient, Credentials, EnvCredentials, Environment};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let credentials = Credentials::from_provider(EnvCredentials::default())?;
+    ///     let client = MarginClient::connect_with_credentials(credentials, Environment::Binance)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_credentials<U>(credentials: Credentials, url: U) -> crate::error::Result<Self> 
+    where
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials,
+            url,
+            client,
+            settings
+        })
+    }
+    /// Credentials of this client, shared with the clients converted from it.
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+    /// Borrow an asset.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let transaction = client
+    ///     .borrow("USDT", "100.00".parse()?)
+    ///     // optional: borrow on the isolated account of the symbol; default is cross margin.
+    ///     .with_isolated_account("BNBUSDT")
+    ///     .transaction()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn borrow<'a>(&self, asset: &'a str, amount: Number) -> ParamBuilder<'a, '_, LoanParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/borrow-repay").unwrap();
+
+        ParamBuilder::new(
+            Parameters {
+                asset: Some(asset),
+                amount: Some(amount),
+                is_isolated: Some(false),
+                loan_type: Some(LoanType::Borrow),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Repay a loan, interest first.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let transaction = client
+    ///     .repay("USDT", "100.00".parse()?)
+    ///     .transaction()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn repay<'a>(&self, asset: &'a str, amount: Number) -> ParamBuilder<'a, '_, LoanParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/borrow-repay").unwrap();
+
+        ParamBuilder::new(
+            Parameters {
+                asset: Some(asset),
+                amount: Some(amount),
+                is_isolated: Some(false),
+                loan_type: Some(LoanType::Repay),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Place a new limit order; margin orders can't be sent as test.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use tokio_binance::{Side::Buy, SideEffectType::MarginBuy};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let order = client
+    ///     .place_limit_order("BNBUSDT", Buy, "20.00".parse()?, "5.00".parse()?)
+    ///     // optional: trade on the isolated account of the symbol; default is cross margin.
+    ///     .with_isolated(true)
+    ///     // optional: borrow what the order needs; default is NoSideEffect.
+    ///     .with_side_effect_type(MarginBuy)
+    ///     // optional: unique id; auto generated by default.
+    ///     .with_new_client_order_id("<uuid>")
+    ///     // optional: converts Limit to Stop-Limit; triggers when price hits below 21.00.
+    ///     .with_stop_loss_limit("21.00".parse()?)
+    ///     // optional: processing time for request; default is 5000, can't be above 60000.
+    ///     .with_recv_window(8000)
+    ///     //
+    ///     .result()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn place_limit_order<'a>(
+        &self, symbol: &'a str,
+        side: Side,
+        price: Number,
+        quantity: Number
+    ) -> ParamBuilder<'a, '_, MarginLimitOrderParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/order").unwrap();
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                side: Some(side),
+                order_type: Some(OrderType::Limit),
+                price: Some(price),
+                quantity: Some(quantity),
+                time_in_force: Some(TimeInForce::Gtc),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Place a new market order; margin orders can't be sent as test.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use tokio_binance::{Side::Sell, SideEffectType::AutoRepay};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let order = client
+    ///     .place_market_order("BNBUSDT", Sell, "5.00".parse()?)
+    ///     // optional: repay debt with the proceeds; default is NoSideEffect.
+    ///     .with_side_effect_type(AutoRepay)
+    ///     .ack()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn place_market_order<'a>(
+        &self, symbol: &'a str,
+        side: Side,
+        quantity: Number
+    ) -> ParamBuilder<'a, '_, MarginMarketOrderParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/order").unwrap();
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                side: Some(side),
+                order_type: Some(OrderType::Market),
+                quantity: Some(quantity),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Get order.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use tokio_binance::ID;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let order = client
+    ///     .get_order("BNBUSDT", ID::ClientOId("<uuid>"))
+    ///     // optional: look up on the isolated account of the symbol.
+    ///     .with_isolated(true)
+    ///     .order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, MarginOrderStatusParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/order").unwrap();
+
+        let order_id = if let ID::OrderId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        let orig_client_order_id = if let ID::ClientOId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                order_id,
+                orig_client_order_id,
+                ..Parameters::default()
+            },
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Cancel order.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use tokio_binance::ID;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let order = client
+    ///     .cancel_order("BNBUSDT", ID::OrderId(1))
+    ///     .canceled_order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, MarginCancelOrderParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/order").unwrap();
+
+        let order_id = if let ID::OrderId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        let orig_client_order_id = if let ID::ClientOId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                order_id,
+                orig_client_order_id,
+                ..Parameters::default()
+            },
+            client.delete(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Get cross margin account details.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let account = client
+    ///     .get_account()
+    ///     .account()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_account(&self) -> ParamBuilder<'_, '_, MarginAccountParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/account").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Get isolated margin account details of every symbol.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let account = client.get_isolated_account().account().await?;
+    ///
+    /// for pair in account.assets {
+    ///     println!("{}: liquidated at {}", pair.symbol, pair.liquidate_price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_isolated_account(&self) -> ParamBuilder<'_, '_, IsolatedMarginAccountParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/isolated/account").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Get the most of an asset that can be borrowed.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let max = client
+    ///     .get_max_borrowable("USDT")
+    ///     // optional: query the isolated account of the symbol; default is cross margin.
+    ///     .with_isolated_symbol("BNBUSDT")
+    ///     .amount()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_max_borrowable<'a>(&self, asset: &'a str) -> ParamBuilder<'a, '_, MaxBorrowableParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/maxBorrowable").unwrap();
+
+        ParamBuilder::new(
+            Parameters { asset: Some(asset), ..Parameters::default() },
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Get the most of an asset that can be transferred out of the margin account.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let max = client
+    ///     .get_max_transferable("BNB")
+    ///     .amount()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_max_transferable<'a>(&self, asset: &'a str) -> ParamBuilder<'a, '_, MaxTransferableParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/maxTransferable").unwrap();
+
+        ParamBuilder::new(
+            Parameters { asset: Some(asset), ..Parameters::default() },
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Get interest charged on loans.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use chrono::{Utc, Duration};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let start = Utc::now() - Duration::days(7);
+    /// let page = client
+    ///     .get_interest_history()
+    ///     // optional: filter by asset.
+    ///     .with_asset("USDT")
+    ///     // optional: get records from; default is 7 days ago, at most 30 days back.
+    ///     .with_start_time(start)
+    ///     // optional: page of the records, starting at 1.
+    ///     .with_current(1)
+    ///     // optional: records per page; default 10, max 100.
+    ///     .with_size(100)
+    ///     .interest()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_interest_history(&self) -> ParamBuilder<'_, '_, InterestHistoryParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/interestHistory").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Get orders placed by forced liquidations.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let page = client
+    ///     .get_force_liquidation_history()
+    ///     .with_isolated_symbol("BNBUSDT")
+    ///     .liquidations()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_force_liquidation_history(&self) -> ParamBuilder<'_, '_, ForceLiquidationParams>{
+        let Self { ref credentials, url, client, settings } = self;
+
+        let url = url.join("/sapi/v1/margin/forceLiquidationRec").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Start a new margin user data stream.
+    /// The stream will close after 60 minutes unless a keepalive is sent.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let listen_key = client
+    ///     .start_stream()
+    ///     .listen_key()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start_stream(&self) -> ParamBuilder<'_, '_, StartStreamParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/userDataStream").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.post(url),
+            Security::ApiKey(credentials),
+            settings
+        )
+    }
+    /// Keepalive a margin user data stream to prevent a time out.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let response = client
+    ///     .keep_alive("<listen-key>")
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn keep_alive<'a>(&self, listen_key: &'a str) -> ParamBuilder<'a, '_, KeepAliveStreamParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/userDataStream").unwrap();
+
+        ParamBuilder::new(
+            Parameters { listen_key: Some(listen_key), ..Parameters::default() },
+            client.put(url),
+            Security::ApiKey(credentials),
+            settings
+        )
+    }
+    /// Close out a margin user data stream.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{MarginClient, Environment};
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+    /// let response = client
+    ///     .close_stream("<listen-key>")
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn close_stream<'a>(&self, listen_key: &'a str) -> ParamBuilder<'a, '_, CloseStreamParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/userDataStream").unwrap();
+
+        ParamBuilder::new(
+            Parameters { listen_key: Some(listen_key), ..Parameters::default() },
+            client.delete(url),
+            Security::ApiKey(credentials),
+            settings
+        )
+    }
+    /// Helper method for getting an account client instance.
+    pub fn to_account_client(&self) -> AccountClient {
+        AccountClient {
+            credentials: self.credentials.clone(),
+            url: self.url.clone(),
+            client: self.client.clone(),
+            settings: self.settings.clone()
+        }
+    }
+}
@@ -4,16 +4,22 @@ use crate::param::{
     Interval,
 };
 use crate::builder::ParamBuilder;
+use crate::environment::RestUrl;
+use crate::credentials::{ApiKey, Credentials, Security};
+use super::Settings;
 use crate::types::*;
 
 /// Client for dealing with market data.
 #[derive(Clone)]
 pub struct MarketDataClient {
-    pub(super) api_key: String,
+    pub(super) credentials: Credentials,
     pub(super) url: Url,
     pub(super) client: Client,
+    pub(super) settings: Settings,
 }
 
+settings_setters!(MarketDataClient, "use tokio_binance::{MarketDataClient, BINANCE_US_URL};", "MarketDataClient::connect(\"<api-key>\", BINANCE_US_URL)?", "BINANCE_US_URL", [rate_limiter, retry_policy, timeout, connect_timeout]);
+
 impl MarketDataClient {
     /// Creates new client instance
     /// # Example
@@ -30,12 +36,16 @@ impl MarketDataClient {
     pub fn connect<A, U>(api_key: A, url: U) -> crate::error::Result<Self> 
     where
         A: Into<String>,
-        U: Into<String>
+        U: Into<RestUrl>
     {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
         Ok(Self {
-            api_key: api_key.into(),
-            url: url.into().parse::<Url>()?,
-            client: Client::new()
+            credentials: Credentials::new(ApiKey::unsigned(api_key)),
+            url,
+            client,
+            settings
         })
     }
     /// Get order book.
@@ -59,14 +69,14 @@ impl MarketDataClient {
     /// # }
     /// ```
     pub fn get_order_book<'a>(&self, symbol: &'a str) -> ParamBuilder<'a, '_, OrderBookParams>{
-        let Self { ref api_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
         let url = url.join("/api/v3/depth").unwrap();
 
         ParamBuilder::new(
             Parameters { symbol: Some(symbol), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// Get recent trades (up to last 500).
@@ -90,14 +100,14 @@ impl MarketDataClient {
     /// # }
     /// ```
     pub fn get_trades<'a>(&self, symbol: &'a str) -> ParamBuilder<'a, '_, TradesParams>{
-        let Self { ref api_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
         let url = url.join("/api/v3/trades").unwrap();
 
         ParamBuilder::new(
             Parameters { symbol: Some(symbol), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// Get older trades.
@@ -123,14 +133,14 @@ impl MarketDataClient {
     /// # }
     /// ```
     pub fn get_historical_trades<'a>(&self, symbol: &'a str) -> ParamBuilder<'a, '_, HistoricalTradesParams>{
-        let Self { ref api_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
         let url = url.join("/api/v3/historicalTrades").unwrap();
 
         ParamBuilder::new(
             Parameters { symbol: Some(symbol), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// Get compressed, aggregate trades. 
@@ -166,14 +176,14 @@ impl MarketDataClient {
     /// # }
     /// ```
     pub fn get_aggregate_trades<'a>(&self, symbol: &'a str) -> ParamBuilder<'a, '_, AggTradesParams>{
-        let Self { ref api_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
         let url = url.join("/api/v3/aggTrades").unwrap();
 
         ParamBuilder::new(
             Parameters { symbol: Some(symbol), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// Kline/candlestick bars for a symbol. Klines are uniquely identified by their open time.
@@ -206,14 +216,14 @@ impl MarketDataClient {
     /// # }
     /// ```
     pub fn get_candlestick_bars<'a>(&self, symbol: &'a str, interval: Interval) -> ParamBuilder<'a, '_, KlinesParams>{
-        let Self { ref api_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
         let url = url.join("/api/v3/klines").unwrap();
 
         ParamBuilder::new(
             Parameters { symbol: Some(symbol), interval: Some(interval), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// Current average price for a symbol.
@@ -234,14 +244,14 @@ impl MarketDataClient {
     /// # }
     /// ```
     pub fn get_average_price<'a>(&self, symbol: &'a str) -> ParamBuilder<'a, '_, AveragePriceParams>{
-        let Self { ref api_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
         let url = url.join("/api/v3/avgPrice").unwrap();
 
         ParamBuilder::new(
             Parameters { symbol: Some(symbol), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// 24 hour rolling window price change statistics. 
@@ -266,14 +276,14 @@ impl MarketDataClient {
     /// # }
     /// ```
     pub fn get_24hr_ticker_price<'a>(&self) -> ParamBuilder<'a, '_, TwentyfourHourTickerPriceParams>{
-        let Self { ref api_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
         let url = url.join("/api/v3/ticker/24hr").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// Latest price for a symbol or symbols.
@@ -297,14 +307,14 @@ impl MarketDataClient {
     /// # }
     /// ```
     pub fn get_price_ticker<'a>(&self) -> ParamBuilder<'a, '_, TickerPriceParams>{
-        let Self { ref api_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
         let url = url.join("/api/v3/ticker/price").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// Best price/qty on the order book for a symbol or symbols.
@@ -328,14 +338,14 @@ impl MarketDataClient {
     /// # }
     /// ```
     pub fn get_order_book_ticker<'a>(&self) -> ParamBuilder<'a, '_, OrderBookTickerParams>{
-        let Self { ref api_key, url, client } = self;
+        let Self { ref credentials, url, client, settings } = self;
         let url = url.join("/api/v3/ticker/bookTicker").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
 }
\ No newline at end of file
@@ -1,14 +1,287 @@
+/// Implements the setters of the `Settings` of a client.
+///
+/// The examples import the client with `$use`, create it with `$connect` and
+/// synchronize time with a `GeneralClient` of `$spot`.
+macro_rules! settings_setters {
+    ($client:ident, $use:literal, $connect:literal, $spot:literal, [$($setter:ident),*]) => {
+        $(settings_setters!(@$setter $client, $use, $connect, $spot);)*
+    };
+    (@rate_limiter $client:ident, $use:literal, $connect:literal, $spot:literal) => {
+        impl $client {
+            /// Use a rate limiter other than the one shared by all clients of the same host.
+            /// # Example
+            ///
+            /// ```no_run
+            #[doc = $use]
+            /// use tokio_binance::RateLimiter;
+            ///
+            /// #[tokio::main]
+            /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+            #[doc = concat!("    let client = ", $connect)]
+            ///         .with_rate_limiter(RateLimiter::new());
+            ///     Ok(())
+            /// }
+            /// ```
+            pub fn with_rate_limiter(mut self, limiter: $crate::rate_limit::RateLimiter) -> Self {
+                self.settings.limiter = limiter;
+                self
+            }
+        }
+    };
+    (@retry_policy $client:ident, $use:literal, $connect:literal, $spot:literal) => {
+        impl $client {
+            /// Retry requests failing with a transient error.
+            /// # Example
+            ///
+            /// ```no_run
+            #[doc = $use]
+            /// use tokio_binance::RetryPolicy;
+            ///
+            /// #[tokio::main]
+            /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+            #[doc = concat!("    let client = ", $connect)]
+            ///         .with_retry_policy(RetryPolicy::default());
+            ///     Ok(())
+            /// }
+            /// ```
+            pub fn with_retry_policy(mut self, policy: $crate::retry::RetryPolicy) -> Self {
+                self.settings.retry = Some(policy);
+                self
+            }
+        }
+    };
+    (@timeout $client:ident, $use:literal, $connect:literal, $spot:literal) => {
+        impl $client {
+            /// Time until a request fails without a response.
+            /// # Example
+            ///
+            /// ```no_run
+            #[doc = $use]
+            /// use std::time::Duration;
+            ///
+            /// #[tokio::main]
+            /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+            #[doc = concat!("    let client = ", $connect)]
+            ///         .with_timeout(Duration::from_secs(10));
+            ///     Ok(())
+            /// }
+            /// ```
+            pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+                self.settings.timeout = Some(timeout);
+                self
+            }
+        }
+    };
+    (@connect_timeout $client:ident, $use:literal, $connect:literal, $spot:literal) => {
+        impl $client {
+            /// Time until connecting fails; this client no longer shares connections with the others.
+            /// Fails for clients sharing a custom HTTP client, see `BinanceClientBuilder::with_client`.
+            /// # Example
+            ///
+            /// ```no_run
+            #[doc = $use]
+            /// use std::time::Duration;
+            ///
+            /// #[tokio::main]
+            /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+            #[doc = concat!("    let client = ", $connect)]
+            ///         .with_connect_timeout(Duration::from_secs(3))?;
+            ///     Ok(())
+            /// }
+            /// ```
+            pub fn with_connect_timeout(mut self, timeout: std::time::Duration) -> $crate::error::Result<Self> {
+                self.client = self.settings.set_connect_timeout(timeout)?;
+                Ok(self)
+            }
+        }
+    };
+    (@recv_window $client:ident, $use:literal, $connect:literal, $spot:literal) => {
+        impl $client {
+            /// Processing time of signed requests that don't set one with `with_recv_window`;
+            /// default is 5000, fails above 60000.
+            /// # Example
+            ///
+            /// ```no_run
+            #[doc = $use]
+            ///
+            /// #[tokio::main]
+            /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+            #[doc = concat!("    let client = ", $connect)]
+            ///         .with_recv_window(8000)?;
+            ///     Ok(())
+            /// }
+            /// ```
+            pub fn with_recv_window(mut self, recv_window: usize) -> $crate::error::Result<Self> {
+                $crate::client::check_recv_window(recv_window)?;
+                self.settings.recv_window = Some(recv_window);
+                Ok(self)
+            }
+        }
+    };
+    (@time_sync $client:ident, $use:literal, $connect:literal, $spot:literal) => {
+        impl $client {
+            /// Stamp signed requests with the server time instead of the local clock.
+            /// # Example
+            ///
+            /// ```no_run
+            #[doc = $use]
+            /// use tokio_binance::{GeneralClient, TimeSync};
+            ///
+            /// #[tokio::main]
+            /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+            #[doc = concat!("    let time_sync = TimeSync::new(GeneralClient::connect(", $spot, ")?);")]
+            #[doc = concat!("    let client = ", $connect)]
+            ///         .with_time_sync(time_sync);
+            ///     Ok(())
+            /// }
+            /// ```
+            pub fn with_time_sync(mut self, time_sync: $crate::time_sync::TimeSync) -> Self {
+                self.settings.time_sync = Some(time_sync);
+                self
+            }
+        }
+    };
+}
+
 mod account;
+mod binance;
+mod delivery;
+mod futures;
 mod general;
+mod margin;
 mod market;
 mod user_data;
 mod withdraw;
+mod ws_api;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use reqwest::{Client, Proxy, Request, Url};
+use crate::environment::RestUrl;
+use crate::error::{Error, Kind};
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::time_sync::TimeSync;
 
 /// https://api.binance.us
-pub const BINANCE_US_URL: &'static str = "https://api.binance.us";
+pub const BINANCE_US_URL: &str = "https://api.binance.us";
+/// Longest processing time Binance accepts for a signed request, in milliseconds.
+pub const MAX_RECV_WINDOW: usize = 60_000;
 
 pub use self::account::AccountClient;
+pub use self::binance::{BinanceClient, BinanceClientBuilder};
+pub use self::delivery::DeliveryClient;
+pub use self::futures::FuturesClient;
 pub use self::market::MarketDataClient;
 pub use self::general::GeneralClient;
+pub use self::margin::MarginClient;
 pub use self::user_data::UserDataClient;
-pub use self::withdraw::WithdrawalClient;
\ No newline at end of file
+pub use self::withdraw::WithdrawalClient;
+pub use self::ws_api::{WsApiClient, BINANCE_US_WS_API_URL};
+pub(crate) use self::ws_api::WsApi;
+
+/// Request settings shared by a client and the builders it creates.
+#[derive(Clone)]
+pub(crate) struct Settings {
+    pub client: Client,
+    pub limiter: RateLimiter,
+    pub time_sync: Option<TimeSync>,
+    pub retry: Option<RetryPolicy>,
+    pub user_agent: String,
+    pub timeout: Option<Duration>,
+    pub recv_window: Option<usize>,
+    pub connect_timeout: Option<Duration>,
+    pub proxy: Option<Proxy>,
+    pub custom_client: bool,
+    pub failover: Failover,
+}
+
+impl Settings {
+    /// Settings of a client of `url`; returns its primary base URL.
+    fn new(url: RestUrl, client: &Client) -> crate::error::Result<(Url, Self)> {
+        let failover = Failover::new(&url)?;
+        let url = failover.urls[0].clone();
+
+        Ok((url.clone(), Self {
+            client: client.clone(),
+            limiter: RateLimiter::shared(url.host_str().unwrap_or_default()),
+            time_sync: None,
+            retry: None,
+            user_agent: "tokio-binance".into(),
+            timeout: None,
+            recv_window: None,
+            connect_timeout: None,
+            proxy: None,
+            custom_client: false,
+            failover,
+        }))
+    }
+    /// Replace the HTTP client with one connecting within `timeout`.
+    fn set_connect_timeout(&mut self, timeout: Duration) -> crate::error::Result<Client> {
+        if self.custom_client {
+            return Err(Error::new(Kind::Config, Some("the connect timeout of a custom client can't be changed")));
+        }
+
+        self.connect_timeout = Some(timeout);
+        self.client = http_client(self.proxy.clone(), self.connect_timeout)?;
+        Ok(self.client.clone())
+    }
+}
+
+/// Base URLs of a client; requests that can't connect are sent to the next one.
+#[derive(Clone, Debug)]
+pub(crate) struct Failover {
+    urls: Arc<[Url]>,
+    current: Arc<AtomicUsize>,
+}
+
+impl Failover {
+    fn new(url: &RestUrl) -> crate::error::Result<Self> {
+        let urls = std::iter::once(&url.url)
+            .chain(&url.fallbacks)
+            .map(|url| url.parse::<Url>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { urls: urls.into(), current: Arc::new(AtomicUsize::new(0)) })
+    }
+    /// Number of base URLs.
+    pub fn len(&self) -> usize {
+        self.urls.len()
+    }
+    /// Send `request` to the current base URL; returns its index.
+    pub fn apply(&self, request: &mut Request) -> usize {
+        let index = self.current.load(Ordering::Relaxed);
+        let mut url = self.urls[index].clone();
+
+        url.set_path(request.url().path());
+        url.set_query(request.url().query());
+        *request.url_mut() = url;
+        index
+    }
+    /// Move on from the base URL at `index`, unless another request already did.
+    pub fn fail(&self, index: usize) {
+        let next = (index + 1) % self.urls.len();
+        let _ = self.current.compare_exchange(index, next, Ordering::Relaxed, Ordering::Relaxed);
+    }
+}
+
+fn http_client(proxy: Option<Proxy>, connect_timeout: Option<Duration>) -> crate::error::Result<Client> {
+    let mut builder = Client::builder();
+
+    if let Some(proxy) = proxy {
+        builder = builder.proxy(proxy);
+    }
+    if let Some(timeout) = connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    Ok(builder.build()?)
+}
+
+pub(crate) fn check_recv_window(recv_window: usize) -> crate::error::Result<()> {
+    if recv_window > MAX_RECV_WINDOW {
+        let message = format!("recv_window of {} is above {}", recv_window, MAX_RECV_WINDOW);
+        return Err(Error::new(Kind::Config, Some(message)));
+    }
+    Ok(())
+}
@@ -1,16 +1,22 @@
 use reqwest::{Url, Client};
 use crate::param::Parameters;
 use crate::builder::ParamBuilder;
+use crate::environment::RestUrl;
+use crate::credentials::{ApiKey, Credentials, Security};
+use super::Settings;
 use crate::types::*;
 
 /// Client for dealing with the user data stream.
 #[derive(Clone)]
 pub struct UserDataClient {
-    api_key: String,
-    url: Url,
-    client: Client
+    pub(super) credentials: Credentials,
+    pub(super) url: Url,
+    pub(super) client: Client,
+    pub(super) settings: Settings,
 }
 
+settings_setters!(UserDataClient, "use tokio_binance::{UserDataClient, BINANCE_US_URL};", "UserDataClient::connect(\"<api-key>\", BINANCE_US_URL)?", "BINANCE_US_URL", [rate_limiter, retry_policy, timeout, connect_timeout]);
+
 impl UserDataClient {
     /// Creates new client instance
     /// # Example
@@ -27,12 +33,16 @@ impl UserDataClient {
     pub fn connect<A, U>(api_key: A, url: U) -> crate::error::Result<Self> 
     where
         A: Into<String>,
-        U: Into<String>
+        U: Into<RestUrl>
     {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
         Ok(Self {
-            api_key: api_key.into(), 
-            url: url.into().parse::<Url>()?,
-            client: Client::new()
+            credentials: Credentials::new(ApiKey::unsigned(api_key)),
+            url,
+            client,
+            settings
         })
     }
     /// Start a new user data stream. 
@@ -54,14 +64,14 @@ impl UserDataClient {
     /// # }
     /// ```
     pub fn start_stream(&self) -> ParamBuilder<'_, '_, StartStreamParams>{
-        let Self { api_key, url, client } = self;
+        let Self { credentials, url, client, settings } = self;
         let url = url.join("/api/v3/userDataStream").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.post(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// Keepalive a user data stream to prevent a time out. 
@@ -84,14 +94,14 @@ impl UserDataClient {
     /// # }
     /// ```
     pub fn keep_alive<'a>(&self, listen_key: &'a str) -> ParamBuilder<'a, '_, KeepAliveStreamParams>{
-        let Self { api_key,  url, client } = self;
+        let Self { credentials, url, client, settings } = self;
         let url = url.join("/api/v3/userDataStream").unwrap();
 
         ParamBuilder::new(
             Parameters { listen_key: Some(listen_key), ..Parameters::default() },
             client.put(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
     /// Close out a user data stream.
@@ -112,14 +122,14 @@ impl UserDataClient {
     /// # }
     /// ```
     pub fn close_stream<'a>(&self, listen_key: &'a str) -> ParamBuilder<'a, '_, CloseStreamParams>{
-        let Self { api_key, url, client } = self;
+        let Self { credentials, url, client, settings } = self;
         let url = url.join("/api/v3/userDataStream").unwrap();
 
         ParamBuilder::new(
             Parameters { listen_key: Some(listen_key), ..Parameters::default() },
             client.delete(url),
-            Some(api_key),
-            None
+            Security::ApiKey(credentials),
+            settings
         )
     }
 }
\ No newline at end of file
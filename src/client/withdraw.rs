@@ -1,19 +1,27 @@
 use reqwest::{Url, Client};
 use crate::param::{
+    Number,
     Parameters, 
+    TransferType,
 };
 use crate::builder::ParamBuilder;
+use crate::environment::RestUrl;
+use crate::credentials::{ApiKey, Credentials, Security};
+use crate::signer::Signer;
+use super::Settings;
 use crate::types::*;
 
 /// Client for dealing with withdrawals and sub accounts.
 #[derive(Clone)]
 pub struct WithdrawalClient {
-    pub(super) api_key: String,
-    pub(super) secret_key: String,
+    pub(super) credentials: Credentials,
     pub(super) url: Url,
-    pub(super) client: Client
+    pub(super) client: Client,
+    pub(super) settings: Settings,
 }
 
+settings_setters!(WithdrawalClient, "use tokio_binance::{WithdrawalClient, BINANCE_US_URL};", "WithdrawalClient::connect(\"<api-key>\", \"<secret-key>\", BINANCE_US_URL)?", "BINANCE_US_URL", [rate_limiter, retry_policy, timeout, connect_timeout, recv_window, time_sync]);
+
 impl WithdrawalClient {
     /// Creates new client instance.
     /// # Example
@@ -31,16 +39,80 @@ impl WithdrawalClient {
     where
         A: Into<String>,
         S: Into<String>,
-        U: Into<String>
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials: Credentials::new(ApiKey::new(api_key, secret_key)),
+            url,
+            client,
+            settings
+        })
+    }
+    /// Creates new client instance signing requests with `signer`, e.g. for RSA or Ed25519 API keys.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{WithdrawalClient, HmacSigner, BINANCE_US_URL};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let signer = HmacSigner::new("<secret-key>");
+    ///     let client = WithdrawalClient::connect_with_signer("<api-key>", signer, BINANCE_US_URL)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_signer<A, S, U>(api_key: A, signer: S, url: U) -> crate::error::Result<Self> 
+    where
+        A: Into<String>,
+        S: Signer + 'static,
+        U: Into<RestUrl>
     {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
         Ok(Self {
-            api_key: api_key.into(), 
-            secret_key: secret_key.into(),
-            url: url.into().parse::<Url>()?,
-            client: Client::new()
+            credentials: Credentials::new(ApiKey::with_signer(api_key, signer)),
+            url,
+            client,
+            settings
         })
     }
+    /// Creates new client instance using `credentials`, which can be rotated without reconnecting.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{WithdrawalClient, Credentials, EnvCredentials, BINANCE_US_URL};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let credentials = Credentials::from_provider(EnvCredentials::default())?;
+    ///     let client = WithdrawalClient::connect_with_credentials(credentials, BINANCE_US_URL)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn connect_with_credentials<U>(credentials: Credentials, url: U) -> crate::error::Result<Self> 
+    where
+        U: Into<RestUrl>
+    {
+        let client = Client::new();
+        let (url, settings) = Settings::new(url.into(), &client)?;
+
+        Ok(Self {
+            credentials,
+            url,
+            client,
+            settings
+        })
+    }
+    /// Credentials of this client, shared with the clients converted from it.
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
     /// Submit a withdraw request.
+    /// Use `get_coins` for the networks an asset can be withdrawn on.
     /// # Example
     ///
     /// ```no_run
@@ -51,7 +123,9 @@ impl WithdrawalClient {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = WithdrawalClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
     /// let response = client
-    ///     .withdraw("BNB", "<public-address>", 5.00)
+    ///     .withdraw("BNB", "<public-address>", "5.00".parse()?)
+    ///     // optional: network to withdraw on; default is the coin's default network.
+    ///     .with_network("BSC")
     ///     //optional: Secondary address identifier for coins like XRP,XMR etc.
     ///     .with_address_tag("<tag>")
     ///     // optional: Description of the address.
@@ -64,20 +138,20 @@ impl WithdrawalClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn withdraw<'a>(&self, asset: &'a str, address: &'a str, amount: f64) -> ParamBuilder<'a, '_, WithdrawParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/withdraw.html").unwrap();
+    pub fn withdraw<'a>(&self, asset: &'a str, address: &'a str, amount: Number) -> ParamBuilder<'a, '_, WithdrawParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/capital/withdraw/apply").unwrap();
 
         ParamBuilder::new(
             Parameters { 
-                asset: Some(asset), 
+                coin: Some(asset), 
                 address: Some(address),
                 amount: Some(amount),
                 ..Parameters::default() 
             },
             client.post(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch deposit history.
@@ -100,6 +174,8 @@ impl WithdrawalClient {
     ///     .with_asset("BNB")
     ///     // optional: 0(0:pending,6: credited but cannot withdraw, 1:success)
     ///     .with_status(1)
+    ///     // optional: limit the amount of deposits; default 1000, max 1000.
+    ///     .with_limit(100)
     ///     // optional: get deposits from; gets the most recent deposits by default.
     ///     .with_start_time(start)
     ///     // optional: get deposits until; default is now.
@@ -113,14 +189,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_deposit_history(&self) -> ParamBuilder<'_, '_, DepositHistoryParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/depositHistory.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/capital/deposit/hisrec").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch withdraw history.
@@ -143,6 +219,8 @@ impl WithdrawalClient {
     ///     .with_asset("BNB")
     ///     // optional: 0(0:Email Sent,1:Cancelled 2:Awaiting Approval 3:Rejected 4:Processing 5:Failure 6Completed)
     ///     .with_status(6)
+    ///     // optional: limit the amount of withdrawals; default 1000, max 1000.
+    ///     .with_limit(100)
     ///     // optional: get deposits from; gets the most recent deposits by default.
     ///     .with_start_time(start)
     ///     // optional: get deposits until; default is now.
@@ -156,14 +234,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_withdraw_history(&self) -> ParamBuilder<'_, '_, WithdrawHistoryParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/withdrawHistory.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/capital/withdraw/history").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch deposit address.
@@ -178,8 +256,8 @@ impl WithdrawalClient {
     /// # let client = WithdrawalClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
     /// let response = client
     ///     .get_deposit_address("BNB")
-    ///     // optional: Boolean.
-    ///     .with_status(true)
+    ///     // optional: network to deposit on; default is the coin's default network.
+    ///     .with_network("BSC")
     ///     // optional: processing time for request; default is 5000, can't be above 60000.
     ///     .with_recv_window(8000)
     ///     //
@@ -189,14 +267,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_deposit_address<'a>(&self, asset: &'a str) -> ParamBuilder<'a, '_, DepositAddressParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/depositAddress.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/capital/deposit/address").unwrap();
 
         ParamBuilder::new(
-            Parameters { asset: Some(asset), ..Parameters::default() },
+            Parameters { coin: Some(asset), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch account status detail.
@@ -220,14 +298,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_account_status(&self) -> ParamBuilder<'_, '_, AccountStatusParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/accountStatus.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/account/status").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch system status.
@@ -248,14 +326,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_system_status(&self) -> ParamBuilder<'_, '_, SystemStatusParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/systemStatus.html").unwrap();
+        let Self { url, client, settings, .. } = self;
+        let url = url.join("/sapi/v1/system/status").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::None,
+            settings
         )
     }
     /// Fetch account api trading status detail.
@@ -279,14 +357,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_api_status(&self) -> ParamBuilder<'_, '_, ApiStatusParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/apiTradingStatus.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/account/apiTradingStatus").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch small amounts of assets exchanged BNB records.
@@ -310,14 +388,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_dustlog(&self) -> ParamBuilder<'_, '_, DustlogParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/userAssetDribbletLog.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/asset/dribblet").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch trade fee.
@@ -343,14 +421,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_trade_fee(&self) -> ParamBuilder<'_, '_, TradeFeeParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/tradeFee.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/asset/tradeFee").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch asset detail.
@@ -365,6 +443,8 @@ impl WithdrawalClient {
     /// # let client = WithdrawalClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
     /// let response = client
     ///     .get_asset_detail()
+    ///     // optional: filter by asset; gets all assets by default.
+    ///     .with_asset("BNB")
     ///     // optional: processing time for request; default is 5000, can't be above 60000.
     ///     .with_recv_window(8000)
     ///     //
@@ -374,14 +454,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_asset_detail(&self) -> ParamBuilder<'_, '_, AssetDetailParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/assetDetail.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/asset/assetDetail").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch sub account list.
@@ -398,8 +478,8 @@ impl WithdrawalClient {
     ///     .get_sub_accounts()
     ///     // optional: Sub-account email.
     ///     .with_email("<email>")
-    ///     // optional: Sub-account status: enabled or disabled.
-    ///     .with_status("enabled")
+    ///     // optional: only frozen sub-accounts; default is every sub-account.
+    ///     .with_is_freeze(true)
     ///     // optional: default value: 1.
     ///     .with_page(2)
     ///     // optional: limit the amount of sub accounts; default 500.
@@ -413,17 +493,18 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_sub_accounts(&self) -> ParamBuilder<'_, '_, SubAccountParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/sub-account/list.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/sub-account/list").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
-    /// Fetch transfer history list
+    /// Fetch transfer history list of transfers out of a sub-account;
+    /// transfers into it are listed by `get_transfer_history_to`.
     /// # Example
     ///
     /// ```no_run
@@ -456,17 +537,48 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_transfer_history<'a>(&self, email: &'a str) -> ParamBuilder<'a, '_, SubAccountTranferParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/sub-account/transfer/history.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/sub-account/sub/transfer/history").unwrap();
 
         ParamBuilder::new(
-            Parameters { email: Some(email), ..Parameters::default() },
+            Parameters { from_email: Some(email), ..Parameters::default() },
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Fetch transfer history list of transfers into a sub-account;
+    /// transfers out of it are listed by `get_transfer_history`.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WithdrawalClient, BINANCE_US_URL};
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WithdrawalClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+    /// let response = client
+    ///     .get_transfer_history_to("<email>")
+    ///     // optional: limit the amount of transfers; default 500.
+    ///     .with_limit(100)
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_transfer_history_to<'a>(&self, email: &'a str) -> ParamBuilder<'a, '_, SubAccountTranferParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/sub-account/sub/transfer/history").unwrap();
+
+        ParamBuilder::new(
+            Parameters { to_email: Some(email), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
-    /// Execute sub-account transfer.
+    /// Execute sub-account transfer between spot wallets.
     /// # Example
     ///
     /// ```no_run
@@ -477,7 +589,7 @@ impl WithdrawalClient {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = WithdrawalClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
     /// let response = client
-    ///     .tranfer_sub_account("<from_email>", "<to_email>", "BNB", 5.00)
+    ///     .tranfer_sub_account("<from_email>", "<to_email>", "BNB", "5.00".parse()?)
     ///     // optional: processing time for request; default is 5000, can't be above 60000.
     ///     .with_recv_window(8000)
     ///     //
@@ -490,22 +602,24 @@ impl WithdrawalClient {
         from_email: &'a str, 
         to_email: &'a str, 
         asset: &'a str, 
-        amount: f64
+        amount: Number
     ) -> ParamBuilder<'a, '_, TransferSubAccountParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/sub-account/transfer.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/sub-account/universalTransfer").unwrap();
 
         ParamBuilder::new(
             Parameters { 
                 from_email: Some(from_email), 
                 to_email: Some(to_email), 
+                from_account_type: Some("SPOT"),
+                to_account_type: Some("SPOT"),
                 asset: Some(asset), 
                 amount: Some(amount), 
                 ..Parameters::default() 
             },
             client.post(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Fetch sub-account assets.
@@ -520,8 +634,6 @@ impl WithdrawalClient {
     /// # let client = WithdrawalClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
     /// let response = client
     ///     .get_sub_account_assets("<email>")
-    ///     // optional: filter by symbol; gets all symbols by default.
-    ///     .with_symbol("BNBUSDT")
     ///     // optional: processing time for request; default is 5000, can't be above 60000.
     ///     .with_recv_window(8000)
     ///     //
@@ -531,14 +643,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_sub_account_assets<'a>(&self, email: &'a str) -> ParamBuilder<'a, '_, SubAccountAssetParams>{
-        let Self { api_key, secret_key, url, client } = self;
-        let url = url.join("/wapi/v3/sub-account/assets.html").unwrap();
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v3/sub-account/assets").unwrap();
 
         ParamBuilder::new(
             Parameters { email: Some(email), ..Parameters::default() },
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Convert dust assets to BNB.
@@ -563,14 +675,14 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn dust_transfer<'a>(&self, asset: &'a str) -> ParamBuilder<'a, '_, DustTransferParams>{
-        let Self { api_key, secret_key, url, client } = self;
+        let Self { credentials, url, client, settings } = self;
         let url = url.join("/sapi/v1/asset/dust").unwrap();
 
         ParamBuilder::new(
             Parameters { asset: Some(asset), ..Parameters::default() },
             client.post(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
         )
     }
     /// Query asset dividend record.
@@ -604,14 +716,128 @@ impl WithdrawalClient {
     /// # }
     /// ```
     pub fn get_asset_dividends(&self) -> ParamBuilder<'_, '_, AssetDividendParams>{
-        let Self { api_key, secret_key, url, client } = self;
+        let Self { credentials, url, client, settings } = self;
         let url = url.join("/sapi/v1/asset/assetDividend").unwrap();
 
         ParamBuilder::new(
             Parameters::default(),
             client.get(url),
-            Some(api_key),
-            Some(secret_key)
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Fetch every coin with its networks and whether it can be deposited or withdrawn on each.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WithdrawalClient, BINANCE_US_URL};
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WithdrawalClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+    /// let coins = client
+    ///     .get_coins()
+    ///     .json::<Value>()
+    ///     .await?;
+    ///
+    /// for coin in coins.as_array().into_iter().flatten().filter(|c| c["coin"] == "USDT") {
+    ///     for network in coin["networkList"].as_array().into_iter().flatten() {
+    ///         println!("{}: withdraw fee {}", network["network"], network["withdrawFee"]);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_coins(&self) -> ParamBuilder<'_, '_, CoinsParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/capital/config/getall").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Transfer an asset between the spot, margin, futures and funding wallets.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WithdrawalClient, BINANCE_US_URL};
+    /// use tokio_binance::TransferType::SpotToUsdMFutures;
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WithdrawalClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+    /// let response = client
+    ///     .universal_transfer(SpotToUsdMFutures, "USDT", "100.00".parse()?)
+    ///     // optional: processing time for request; default is 5000, can't be above 60000.
+    ///     .with_recv_window(8000)
+    ///     //
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn universal_transfer<'a>(&self,
+        transfer_type: TransferType,
+        asset: &'a str,
+        amount: Number
+    ) -> ParamBuilder<'a, '_, UniversalTransferParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/asset/transfer").unwrap();
+
+        ParamBuilder::new(
+            Parameters {
+                transfer_type: Some(transfer_type),
+                asset: Some(asset),
+                amount: Some(amount),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        )
+    }
+    /// Fetch universal transfer history of one transfer type.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WithdrawalClient, BINANCE_US_URL};
+    /// use tokio_binance::TransferType::UsdMFuturesToSpot;
+    /// use chrono::{Utc, Duration};
+    /// use serde_json::Value;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WithdrawalClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+    /// let start = Utc::now() - Duration::days(7);
+    ///
+    /// let response = client
+    ///     .get_universal_transfer_history(UsdMFuturesToSpot)
+    ///     // optional: get transfers from; default is 7 days ago.
+    ///     .with_start_time(start)
+    ///     // optional: page of the transfers, starting at 1.
+    ///     .with_current(1)
+    ///     // optional: transfers per page; default 10, max 100.
+    ///     .with_size(100)
+    ///     //
+    ///     .json::<Value>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_universal_transfer_history(&self, transfer_type: TransferType) -> ParamBuilder<'_, '_, UniversalTransferHistoryParams>{
+        let Self { credentials, url, client, settings } = self;
+        let url = url.join("/sapi/v1/asset/transfer").unwrap();
+
+        ParamBuilder::new(
+            Parameters { transfer_type: Some(transfer_type), ..Parameters::default() },
+            client.get(url),
+            Security::Signed(credentials),
+            settings
         )
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,614 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use async_tungstenite::tokio::connect_async;
+use async_tungstenite::tungstenite::{Error as WsError, Message};
+use futures::{SinkExt, StreamExt};
+use reqwest::{Client, Request, Url};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, oneshot};
+use log::warn;
+use crate::param::{
+    Parameters, Side, OrderType,
+    TimeInForce, ID
+};
+use crate::param::Number;
+use crate::builder::ParamBuilder;
+use crate::environment::{Environment, RestUrl, WsUrl};
+use crate::credentials::{ApiKey, Credentials, Security};
+use crate::model::{RateLimitInterval, RateLimitType, RateLimitUsage};
+use crate::signer::Signer;
+use super::Settings;
+use crate::types::*;
+
+/// wss://ws-api.binance.us:443/ws-api/v3
+pub const BINANCE_US_WS_API_URL: &str = "wss://ws-api.binance.us:443/ws-api/v3";
+
+/// Client for dealing with orders over the WebSocket API.
+///
+/// Requests are built like those of [`AccountClient`](crate::AccountClient) and
+/// share one connection, which is opened again when it closes. Responses are
+/// matched to their request by id, so requests may be sent concurrently.
+/// Requests count against the rate limiter of the REST API of the same
+/// environment, and are not retried.
+#[derive(Clone)]
+pub struct WsApiClient {
+    credentials: Credentials,
+    url: Url,
+    client: Client,
+    settings: Settings,
+    connection: Arc<WsApi>,
+}
+
+settings_setters!(WsApiClient, "use tokio_binance::{WsApiClient, BINANCE_US_URL, BINANCE_US_WS_API_URL};", "WsApiClient::connect(\"<api-key>\", \"<secret-key>\", BINANCE_US_WS_API_URL).await?", "BINANCE_US_URL", [rate_limiter, timeout, recv_window, time_sync]);
+
+impl WsApiClient {
+    /// Creates new client instance
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{WsApiClient, BINANCE_US_WS_API_URL};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WsApiClient::connect("<api-key>", "<secret-key>", BINANCE_US_WS_API_URL).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn connect<A, S, U>(api_key: A, secret_key: S, url: U) -> crate::error::Result<Self>
+    where
+        A: Into<String>,
+        S: Into<String>,
+        U: Into<WsUrl>
+    {
+        Self::connect_with_credentials(Credentials::new(ApiKey::new(api_key, secret_key)), url).await
+    }
+    /// Creates new client instance signing requests with `signer`, e.g. an Ed25519 key.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{WsApiClient, HmacSigner, BINANCE_US_WS_API_URL};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let signer = HmacSigner::new("<secret-key>");
+    ///     let client = WsApiClient::connect_with_signer("<api-key>", signer, BINANCE_US_WS_API_URL).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn connect_with_signer<A, S, U>(api_key: A, signer: S, url: U) -> crate::error::Result<Self>
+    where
+        A: Into<String>,
+        S: Signer + 'static,
+        U: Into<WsUrl>
+    {
+        Self::connect_with_credentials(Credentials::new(ApiKey::with_signer(api_key, signer)), url).await
+    }
+    /// Creates new client instance with shared credentials.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{WsApiClient, Credentials, ApiKey, BINANCE_US_WS_API_URL};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let credentials = Credentials::new(ApiKey::new("<api-key>", "<secret-key>"));
+    ///     let client = WsApiClient::connect_with_credentials(credentials, BINANCE_US_WS_API_URL).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn connect_with_credentials<U>(credentials: Credentials, url: U) -> crate::error::Result<Self>
+    where
+        U: Into<WsUrl>
+    {
+        let WsUrl { urls } = url.into();
+        let client = Client::new();
+        // Requests are described by their REST endpoint; the base URL only keys the rate limiter,
+        // which is shared with the REST API of the same environment.
+        let (url, settings) = Settings::new(rest_url(&urls[0]), &client)?;
+        let connection = WsApi::connect(urls).await?;
+
+        Ok(Self {
+            credentials,
+            url,
+            client,
+            settings,
+            connection: Arc::new(connection),
+        })
+    }
+    /// Credentials of the client; rotating them affects every clone.
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+    /// Usage of the rate limits, as reported by the last response.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WsApiClient, BINANCE_US_WS_API_URL};
+    /// use tokio_binance::ID;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WsApiClient::connect("<api-key>", "<secret-key>", BINANCE_US_WS_API_URL).await?;
+    /// let order = client.get_order("BNBUSDT", ID::OrderId(1)).order().await?;
+    ///
+    /// for usage in client.rate_limits() {
+    ///     println!("{:?}: {}/{}", usage.rate_limit_type, usage.count, usage.limit);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rate_limits(&self) -> Vec<RateLimitUsage> {
+        self.connection.rate_limits.lock().unwrap().clone()
+    }
+    /// Place a new limit order with `order.place`, or `order.test` when not executed.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WsApiClient, BINANCE_US_WS_API_URL};
+    /// use tokio_binance::{Side::Sell, TimeInForce::Fok};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WsApiClient::connect("<api-key>", "<secret-key>", BINANCE_US_WS_API_URL).await?;
+    /// let order = client
+    ///     // false will send as test, true will send as a real order.
+    ///     .place_limit_order("BNBUSDT", Sell, "20.00".parse()?, "5.00".parse()?, true)
+    ///     // optional: lifetime of order; default is Gtc.
+    ///     .with_time_in_force(Fok)
+    ///     .full()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn place_limit_order<'a>(
+        &self, symbol: &'a str,
+        side: Side,
+        price: Number,
+        quantity: Number,
+        execute: bool
+    ) -> ParamBuilder<'a, '_, LimitOrderParams>{
+        let Self { ref credentials, url, client, settings, connection } = self;
+
+        let url = if execute {
+            url.join("/api/v3/order").unwrap()
+        } else {
+            url.join("/api/v3/order/test").unwrap()
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                side: Some(side),
+                order_type: Some(OrderType::Limit),
+                price: Some(price),
+                quantity: Some(quantity),
+                time_in_force: Some(TimeInForce::Gtc),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        ).over(connection)
+    }
+    /// Place a new market order with `order.place`, or `order.test` when not executed.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WsApiClient, BINANCE_US_WS_API_URL};
+    /// use tokio_binance::Side::Buy;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WsApiClient::connect("<api-key>", "<secret-key>", BINANCE_US_WS_API_URL).await?;
+    /// let order = client
+    ///     .place_market_order("BNBUSDT", Buy, "5.00".parse()?, true)
+    ///     .result()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn place_market_order<'a>(
+        &self, symbol: &'a str,
+        side: Side,
+        quantity: Number,
+        execute: bool
+    ) -> ParamBuilder<'a, '_, MarketOrderParams>{
+        let Self { ref credentials, url, client, settings, connection } = self;
+
+        let url = if execute {
+            url.join("/api/v3/order").unwrap()
+        } else {
+            url.join("/api/v3/order/test").unwrap()
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                side: Some(side),
+                order_type: Some(OrderType::Market),
+                quantity: Some(quantity),
+                ..Parameters::default()
+            },
+            client.post(url),
+            Security::Signed(credentials),
+            settings
+        ).over(connection)
+    }
+    /// Check an order's status with `order.status`.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WsApiClient, BINANCE_US_WS_API_URL};
+    /// use tokio_binance::ID;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WsApiClient::connect("<api-key>", "<secret-key>", BINANCE_US_WS_API_URL).await?;
+    /// let order = client
+    ///     .get_order("BNBUSDT", ID::ClientOId("<uuid>"))
+    ///     .order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, OrderStatusParams>{
+        let Self { ref credentials, url, client, settings, connection } = self;
+
+        let url = url.join("/api/v3/order").unwrap();
+
+        let order_id = if let ID::OrderId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        let orig_client_order_id = if let ID::ClientOId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                order_id,
+                orig_client_order_id,
+                ..Parameters::default()
+            },
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        ).over(connection)
+    }
+    /// Cancel an active order with `order.cancel`.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WsApiClient, BINANCE_US_WS_API_URL};
+    /// use tokio_binance::ID;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WsApiClient::connect("<api-key>", "<secret-key>", BINANCE_US_WS_API_URL).await?;
+    /// let order = client
+    ///     .cancel_order("BNBUSDT", ID::ClientOId("<uuid>"))
+    ///     .canceled_order()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel_order<'a>(&self, symbol: &'a str, id: ID<'a>) -> ParamBuilder<'a, '_, CancelOrderParams>{
+        let Self { ref credentials, url, client, settings, connection } = self;
+
+        let url = url.join("/api/v3/order").unwrap();
+
+        let order_id = if let ID::OrderId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        let orig_client_order_id = if let ID::ClientOId(id) = id {
+            Some(id)
+        } else {
+            None
+        };
+
+        ParamBuilder::new(
+            Parameters {
+                symbol: Some(symbol),
+                order_id,
+                orig_client_order_id,
+                ..Parameters::default()
+            },
+            client.delete(url),
+            Security::Signed(credentials),
+            settings
+        ).over(connection)
+    }
+    /// Get current account information with `account.status`.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WsApiClient, BINANCE_US_WS_API_URL};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = WsApiClient::connect("<api-key>", "<secret-key>", BINANCE_US_WS_API_URL).await?;
+    /// let account = client
+    ///     .get_account()
+    ///     .account()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_account(&self) -> ParamBuilder<'_, '_, AccountParams>{
+        let Self { ref credentials, url, client, settings, connection } = self;
+
+        let url = url.join("/api/v3/account").unwrap();
+
+        ParamBuilder::new(
+            Parameters::default(),
+            client.get(url),
+            Security::Signed(credentials),
+            settings
+        ).over(connection)
+    }
+}
+
+/// Response of the WebSocket API to a request.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Reply {
+    id: Option<Value>,
+    pub status: u16,
+    #[serde(default)]
+    pub result: Value,
+    pub error: Option<Value>,
+    rate_limits: Option<Vec<RateLimitUsage>>,
+}
+
+impl Reply {
+    /// The rate limit usage and `retryAfter` of the reply as the headers of a REST response,
+    /// for the rate limiter.
+    pub fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+
+        for usage in self.rate_limits.iter().flatten() {
+            let prefix = match usage.rate_limit_type {
+                RateLimitType::RequestWeight => "x-mbx-used-weight-",
+                RateLimitType::Orders => "x-mbx-order-count-",
+                RateLimitType::RawRequests => continue,
+            };
+            let unit = match usage.interval {
+                RateLimitInterval::Second => "s",
+                RateLimitInterval::Minute => "m",
+                RateLimitInterval::Day => "d",
+            };
+            let name = format!("{}{}{}", prefix, usage.interval_num, unit);
+
+            if let Ok(name) = HeaderName::from_bytes(name.as_bytes()) {
+                headers.insert(name, HeaderValue::from(usage.count));
+            }
+        }
+
+        // Time in milliseconds when a 429 or 418 is lifted.
+        let retry_after = self.error.as_ref()
+            .and_then(|error| error.pointer("/data/retryAfter"))
+            .and_then(Value::as_u64);
+
+        if let Some(retry_after) = retry_after {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+            let seconds = (retry_after.saturating_sub(now) + 999) / 1000;
+            headers.insert(RETRY_AFTER, HeaderValue::from(seconds));
+        }
+        headers
+    }
+}
+
+struct Call {
+    id: u64,
+    text: String,
+    reply: oneshot::Sender<crate::error::Result<Reply>>,
+}
+
+/// Connection to the WebSocket API shared by the clones of a client.
+pub(crate) struct WsApi {
+    urls: Vec<String>,
+    calls: tokio::sync::Mutex<mpsc::UnboundedSender<Call>>,
+    next_id: AtomicU64,
+    rate_limits: Arc<Mutex<Vec<RateLimitUsage>>>,
+}
+
+impl WsApi {
+    async fn connect(urls: Vec<String>) -> crate::error::Result<Self> {
+        let rate_limits = Arc::new(Mutex::new(Vec::new()));
+        let calls = open(&urls, rate_limits.clone()).await?;
+
+        Ok(Self {
+            urls,
+            calls: tokio::sync::Mutex::new(calls),
+            next_id: AtomicU64::new(1),
+            rate_limits,
+        })
+    }
+    /// WebSocket API method of a request to a REST endpoint.
+    pub fn method(request: &Request) -> Option<&'static str> {
+        match (request.method().as_str(), request.url().path()) {
+            ("POST", "/api/v3/order") => Some("order.place"),
+            ("POST", "/api/v3/order/test") => Some("order.test"),
+            ("GET", "/api/v3/order") => Some("order.status"),
+            ("DELETE", "/api/v3/order") => Some("order.cancel"),
+            ("GET", "/api/v3/account") => Some("account.status"),
+            _ => None,
+        }
+    }
+    /// Send the request `method`, reconnecting first if the connection was closed.
+    pub async fn call(
+        &self,
+        method: &str,
+        params: &BTreeMap<String, String>,
+        timeout: Option<Duration>
+    ) -> crate::error::Result<Reply> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let text = json!({ "id": id, "method": method, "params": params }).to_string();
+        let (reply, response) = oneshot::channel();
+
+        {
+            let mut calls = self.calls.lock().await;
+
+            if calls.is_closed() {
+                *calls = open(&self.urls, self.rate_limits.clone()).await?;
+            }
+            calls.send(Call { id, text, reply }).map_err(|_| WsError::ConnectionClosed)?;
+        }
+
+        let response = async {
+            response.await.unwrap_or_else(|_| Err(WsError::ConnectionClosed.into()))
+        };
+
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, response)
+                .await
+                .unwrap_or_else(|_| Err(WsError::Io(io::ErrorKind::TimedOut.into()).into())),
+            None => response.await,
+        }
+    }
+}
+
+/// REST API of the environment of the WebSocket API at `url`, or `url` itself if it's unknown.
+fn rest_url(url: &str) -> RestUrl {
+    let host = |url: &str| Url::parse(url).ok().and_then(|url| url.host_str().map(String::from));
+    let environment = [Environment::Binance, Environment::BinanceUs, Environment::SpotTestnet]
+        .iter()
+        .find(|environment| environment.ws_api_url().and_then(host).is_some_and(|ws_api| Some(ws_api) == host(url)));
+
+    match environment {
+        Some(environment) => (*environment).into(),
+        None => url.into(),
+    }
+}
+
+/// Connect to the first reachable URL and hand the connection to a background task.
+async fn open(
+    urls: &[String],
+    rate_limits: Arc<Mutex<Vec<RateLimitUsage>>>
+) -> crate::error::Result<mpsc::UnboundedSender<Call>> {
+    let mut error = None;
+
+    for url in urls {
+        match connect_async(url.as_str()).await {
+            Ok((socket, _)) => {
+                let (calls, receiver) = mpsc::unbounded_channel();
+                tokio::spawn(run(socket, receiver, rate_limits));
+                return Ok(calls);
+            }
+            Err(e) => {
+                warn!("Failed to connect to {}: {}", url, e);
+                error = Some(e);
+            }
+        }
+    }
+
+    Err(error.expect("at least one url").into())
+}
+
+/// Send calls and route each response to the call with its id, until the connection
+/// closes or every client is dropped.
+async fn run<S>(
+    mut socket: S,
+    mut calls: mpsc::UnboundedReceiver<Call>,
+    rate_limits: Arc<Mutex<Vec<RateLimitUsage>>>
+)
+where
+    S: futures::Stream<Item = Result<Message, WsError>> + futures::Sink<Message, Error = WsError> + Unpin
+{
+    let mut pending: HashMap<u64, oneshot::Sender<crate::error::Result<Reply>>> = HashMap::new();
+
+    loop {
+        tokio::select! {
+            call = calls.recv() => match call {
+                Some(Call { id, text, reply }) => match socket.send(Message::Text(text)).await {
+                    Ok(()) => {
+                        pending.insert(id, reply);
+                    }
+                    Err(e) => {
+                        let _ = reply.send(Err(e.into()));
+                        break;
+                    }
+                },
+                None => {
+                    let _ = socket.close().await;
+                    break;
+                }
+            },
+            message = socket.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let reply = match serde_json::from_str::<Reply>(&text) {
+                        Ok(reply) => reply,
+                        Err(e) => {
+                            warn!("Unexpected WebSocket API message {}: {}", text, e);
+                            continue;
+                        }
+                    };
+
+                    if let Some(usage) = &reply.rate_limits {
+                        *rate_limits.lock().unwrap() = usage.clone();
+                    }
+
+                    let call = reply.id.as_ref().and_then(Value::as_u64).and_then(|id| pending.remove(&id));
+
+                    match call {
+                        Some(call) => {
+                            let _ = call.send(Ok(reply));
+                        }
+                        None => warn!("WebSocket API response to no request: {}", text),
+                    }
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => {
+                    warn!("WebSocket API connection failed: {}", e);
+                    break;
+                }
+                None => break,
+            }
+        }
+    }
+    // Dropping the pending calls fails them as closed, and the next call reconnects.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+    use crate::rate_limit::{Cost, RateLimiter};
+
+    #[test]
+    fn records_replies() {
+        let reply: Reply = serde_json::from_str(r#"{
+            "id": 1,
+            "status": 418,
+            "error": { "code": -1003, "msg": "Way too many requests", "data": { "retryAfter": 32503680000000 } },
+            "rateLimits": [{ "rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 1200, "count": 1300 }]
+        }"#).unwrap();
+        let request = Client::new().get("https://api.binance.us/api/v3/account").build().unwrap();
+        let limiter = RateLimiter::new();
+
+        limiter.record(Cost::of(&request), StatusCode::IM_A_TEAPOT, &reply.headers(), "");
+
+        assert_eq!(limiter.used_weight(), 1300);
+        assert!(limiter.banned_until().is_some());
+    }
+
+    #[test]
+    fn shares_the_rest_host() {
+        assert_eq!(rest_url(BINANCE_US_WS_API_URL).url, "https://api.binance.us");
+        assert_eq!(rest_url("wss://ws-api.testnet.binance.vision/ws-api/v3").url, "https://testnet.binance.vision");
+        assert_eq!(rest_url("wss://localhost:8443").url, "wss://localhost:8443");
+    }
+}
@@ -0,0 +1,249 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use serde::{Deserialize, Deserializer};
+use zeroize::Zeroizing;
+
+use crate::error::{Error, Kind};
+use crate::signer::{HmacSigner, Signer};
+
+/// Default variable of the API key read by [`EnvCredentials`].
+pub const BINANCE_API_KEY_VAR: &str = "BINANCE_API_KEY";
+/// Default variable of the secret key read by [`EnvCredentials`].
+pub const BINANCE_SECRET_KEY_VAR: &str = "BINANCE_SECRET_KEY";
+
+/// A key that is zeroed when dropped and never printed by `Debug`.
+///
+/// Not `Clone`, so that the key isn't copied around; share it behind an `Arc` instead.
+pub struct Secret(Zeroizing<String>);
+
+impl Secret {
+    pub fn new<S: Into<String>>(secret: S) -> Self {
+        Secret(Zeroizing::new(secret.into()))
+    }
+    /// The key itself.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Secret::new)
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+/// An API key and the signer of its secret.
+pub struct ApiKey {
+    key: Secret,
+    signer: Option<Arc<dyn Signer>>,
+}
+
+impl ApiKey {
+    /// API key signing requests with HMAC-SHA256 and `secret_key`.
+    pub fn new<A: Into<String>, S: Into<String>>(api_key: A, secret_key: S) -> Self {
+        Self::with_signer(api_key, HmacSigner::new(secret_key))
+    }
+    /// API key signing requests with `signer`, e.g. for RSA or Ed25519 API keys.
+    pub fn with_signer<A: Into<String>, S: Signer + 'static>(api_key: A, signer: S) -> Self {
+        Self { key: Secret::new(api_key), signer: Some(Arc::new(signer)) }
+    }
+
+    /// API key signing requests with HMAC-SHA256, moving the keys instead of copying them.
+    pub(crate) fn from_secrets(api_key: Secret, secret_key: Secret) -> Self {
+        Self { key: api_key, signer: Some(Arc::new(HmacSigner::from_secret(secret_key))) }
+    }
+    /// API key of clients that never sign requests.
+    pub(crate) fn unsigned<A: Into<String>>(api_key: A) -> Self {
+        Self { key: Secret::new(api_key), signer: None }
+    }
+    pub(crate) fn key(&self) -> &str {
+        self.key.expose()
+    }
+    pub(crate) fn signer(&self) -> Option<&dyn Signer> {
+        self.signer.as_deref()
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ApiKey")
+            .field("key", &self.key)
+            .field("signed", &self.signer.is_some())
+            .finish()
+    }
+}
+
+/// Source of the API key, loaded on connect and on every [`Credentials::refresh`].
+///
+/// Implemented by [`EnvCredentials`], [`FileCredentials`] and by closures, e.g.
+/// to fetch the key from a vault.
+/// # Example
+///
+/// ```no_run
+/// use tokio_binance::{AccountClient, ApiKey, Credentials, BINANCE_US_URL};
+///
+/// # fn fetch_from_vault(name: &str) -> String { unimplemented!() }
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let credentials = Credentials::from_provider(|| {
+///         Ok(ApiKey::new(fetch_from_vault("api-key"), fetch_from_vault("secret-key")))
+///     })?;
+///     let client = AccountClient::connect_with_credentials(credentials, BINANCE_US_URL)?;
+///     Ok(())
+/// }
+/// ```
+pub trait CredentialsProvider: Send + Sync {
+    /// Load the current API key.
+    fn load(&self) -> crate::error::Result<ApiKey>;
+}
+
+impl<F> CredentialsProvider for F
+where
+    F: Fn() -> crate::error::Result<ApiKey> + Send + Sync,
+{
+    fn load(&self) -> crate::error::Result<ApiKey> {
+        self()
+    }
+}
+
+/// API and secret key read from environment variables, by default
+/// `BINANCE_API_KEY` and `BINANCE_SECRET_KEY`.
+#[derive(Clone, Debug)]
+pub struct EnvCredentials {
+    api_key_var: String,
+    secret_key_var: String,
+}
+
+impl EnvCredentials {
+    pub fn new<A: Into<String>, S: Into<String>>(api_key_var: A, secret_key_var: S) -> Self {
+        Self { api_key_var: api_key_var.into(), secret_key_var: secret_key_var.into() }
+    }
+}
+
+impl Default for EnvCredentials {
+    fn default() -> Self {
+        Self::new(BINANCE_API_KEY_VAR, BINANCE_SECRET_KEY_VAR)
+    }
+}
+
+impl CredentialsProvider for EnvCredentials {
+    fn load(&self) -> crate::error::Result<ApiKey> {
+        let var = |name: &str| {
+            std::env::var(name)
+                .map(Secret::new)
+                .map_err(|e| Error::new(Kind::Credentials, Some(format!("{}: {}", name, e))))
+        };
+
+        let api_key = var(&self.api_key_var)?;
+        let secret_key = var(&self.secret_key_var)?;
+        Ok(ApiKey::from_secrets(api_key, secret_key))
+    }
+}
+
+/// API and secret key read from a JSON file such as
+/// `{"apiKey": "<api-key>", "secretKey": "<secret-key>"}`.
+#[derive(Clone, Debug)]
+pub struct FileCredentials {
+    path: PathBuf,
+}
+
+impl FileCredentials {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl CredentialsProvider for FileCredentials {
+    fn load(&self) -> crate::error::Result<ApiKey> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct File {
+            api_key: Secret,
+            secret_key: Secret,
+        }
+
+        let content = std::fs::read_to_string(&self.path)
+            .map(Secret::new)
+            .map_err(|e| Error::new(Kind::Credentials, Some(format!("{}: {}", self.path.display(), e))))?;
+        let file: File = serde_json::from_str(content.expose())?;
+
+        Ok(ApiKey::from_secrets(file.api_key, file.secret_key))
+    }
+}
+
+/// The API key shared by a client and every client converted from it.
+///
+/// Cloning is cheap; clones share the key, so rotating it applies to all of them.
+/// # Example
+///
+/// ```no_run
+/// use tokio_binance::{AccountClient, Credentials, EnvCredentials, BINANCE_US_URL};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let credentials = Credentials::from_provider(EnvCredentials::default())?;
+///     let client = AccountClient::connect_with_credentials(credentials, BINANCE_US_URL)?;
+///     let withdrawals = client.to_withdraw_client();
+///
+///     // after the environment was updated with a new key.
+///     client.credentials().refresh()?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Credentials {
+    current: Arc<RwLock<Arc<ApiKey>>>,
+    provider: Option<Arc<dyn CredentialsProvider>>,
+}
+
+impl Credentials {
+    /// Fixed API key.
+    pub fn new(api_key: ApiKey) -> Self {
+        Self { current: Arc::new(RwLock::new(Arc::new(api_key))), provider: None }
+    }
+    /// API key loaded from `provider`, now and on every refresh.
+    pub fn from_provider<P: CredentialsProvider + 'static>(provider: P) -> crate::error::Result<Self> {
+        let mut credentials = Self::new(provider.load()?);
+        credentials.provider = Some(Arc::new(provider));
+        Ok(credentials)
+    }
+    /// Load the API key from the provider again; does nothing for a fixed API key.
+    pub fn refresh(&self) -> crate::error::Result<()> {
+        if let Some(provider) = &self.provider {
+            self.rotate(provider.load()?);
+        }
+        Ok(())
+    }
+    /// Replace the API key; requests already sent keep the previous one.
+    pub fn rotate(&self, api_key: ApiKey) {
+        *self.current.write().unwrap() = Arc::new(api_key);
+    }
+
+    pub(crate) fn current(&self) -> Arc<ApiKey> {
+        self.current.read().unwrap().clone()
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("current", &self.current())
+            .field("provider", &self.provider.is_some())
+            .finish()
+    }
+}
+
+/// What a request needs from the credentials of its client.
+#[derive(Copy, Clone)]
+pub(crate) enum Security<'a> {
+    None,
+    ApiKey(&'a Credentials),
+    Signed(&'a Credentials),
+}
@@ -0,0 +1,135 @@
+/// Binance deployment, giving the base URLs of its REST API and websocket streams.
+/// # Example
+///
+/// ```no_run
+/// use tokio_binance::{AccountClient, Channel, Environment, WebSocketStream};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = AccountClient::connect("<api-key>", "<secret-key>", Environment::SpotTestnet)?;
+///     let stream = WebSocketStream::connect(Channel::Ticker("BNBUSDT"), Environment::SpotTestnet).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Environment {
+    /// Binance.com spot, failing over to api1, api2 and api3.binance.com.
+    Binance,
+    /// Binance.US spot.
+    BinanceUs,
+    /// Spot Testnet.
+    SpotTestnet,
+    /// Binance.com USDⓈ-M futures.
+    UsdMFutures,
+    /// USDⓈ-M futures testnet.
+    UsdMFuturesTestnet,
+    /// Binance.com COIN-M futures.
+    CoinMFutures,
+    /// COIN-M futures testnet.
+    CoinMFuturesTestnet,
+}
+
+impl Environment {
+    /// Base URL of the REST API.
+    pub fn rest_url(self) -> &'static str {
+        match self {
+            Environment::Binance => "https://api.binance.com",
+            Environment::BinanceUs => "https://api.binance.us",
+            Environment::SpotTestnet => "https://testnet.binance.vision",
+            Environment::UsdMFutures => "https://fapi.binance.com",
+            Environment::UsdMFuturesTestnet => "https://testnet.binancefuture.com",
+            Environment::CoinMFutures => "https://dapi.binance.com",
+            Environment::CoinMFuturesTestnet => "https://testnet.binancefuture.com",
+        }
+    }
+    /// Alternative base URLs of the REST API, tried in order when the current one can't be reached.
+    pub fn rest_fallback_urls(self) -> &'static [&'static str] {
+        match self {
+            Environment::Binance => &["https://api1.binance.com", "https://api2.binance.com", "https://api3.binance.com"],
+            _ => &[],
+        }
+    }
+    /// Base URL of the websocket streams.
+    pub fn ws_url(self) -> &'static str {
+        match self {
+            Environment::Binance => "wss://stream.binance.com:9443",
+            Environment::BinanceUs => "wss://stream.binance.us:9443",
+            Environment::SpotTestnet => "wss://testnet.binance.vision",
+            Environment::UsdMFutures => "wss://fstream.binance.com",
+            Environment::UsdMFuturesTestnet => "wss://stream.binancefuture.com",
+            Environment::CoinMFutures => "wss://dstream.binance.com",
+            Environment::CoinMFuturesTestnet => "wss://dstream.binancefuture.com",
+        }
+    }
+    /// Alternative base URLs of the websocket streams, tried in order when connecting fails.
+    pub fn ws_fallback_urls(self) -> &'static [&'static str] {
+        match self {
+            Environment::Binance => &["wss://stream.binance.com:443"],
+            Environment::BinanceUs => &["wss://stream.binance.us:443"],
+            _ => &[],
+        }
+    }
+    /// URL of the WebSocket API, for [`WsApiClient`](crate::WsApiClient); futures have none.
+    pub fn ws_api_url(self) -> Option<&'static str> {
+        match self {
+            Environment::Binance => Some("wss://ws-api.binance.com:443/ws-api/v3"),
+            Environment::BinanceUs => Some("wss://ws-api.binance.us:443/ws-api/v3"),
+            Environment::SpotTestnet => Some("wss://ws-api.testnet.binance.vision/ws-api/v3"),
+            _ => None,
+        }
+    }
+}
+
+/// Base URL of a REST API and the alternatives to fail over to; accepted by every `connect`.
+#[derive(Clone, Debug)]
+pub struct RestUrl {
+    pub(crate) url: String,
+    pub(crate) fallbacks: Vec<String>,
+}
+
+impl RestUrl {
+    /// `url`, failing over to `fallbacks` in order when it can't be reached.
+    pub fn with_fallbacks<U: Into<String>>(url: U, fallbacks: &[&str]) -> Self {
+        Self { url: url.into(), fallbacks: fallbacks.iter().map(|url| url.to_string()).collect() }
+    }
+}
+
+impl From<Environment> for RestUrl {
+    fn from(environment: Environment) -> Self {
+        Self::with_fallbacks(environment.rest_url(), environment.rest_fallback_urls())
+    }
+}
+
+impl<U: Into<String>> From<U> for RestUrl {
+    fn from(url: U) -> Self {
+        Self { url: url.into(), fallbacks: Vec::new() }
+    }
+}
+
+/// Base URL of websocket streams and the alternatives to fail over to;
+/// accepted by `WebSocketStream::connect` and `UserDataStream::connect`.
+#[derive(Clone, Debug)]
+pub struct WsUrl {
+    pub(crate) urls: Vec<String>,
+}
+
+impl WsUrl {
+    /// `url`, failing over to `fallbacks` in order when connecting fails.
+    pub fn with_fallbacks<U: Into<String>>(url: U, fallbacks: &[&str]) -> Self {
+        let mut urls = vec![url.into()];
+        urls.extend(fallbacks.iter().map(|url| url.to_string()));
+        Self { urls }
+    }
+}
+
+impl From<Environment> for WsUrl {
+    fn from(environment: Environment) -> Self {
+        Self::with_fallbacks(environment.ws_url(), environment.ws_fallback_urls())
+    }
+}
+
+impl<U: Into<String>> From<U> for WsUrl {
+    fn from(url: U) -> Self {
+        Self { urls: vec![url.into()] }
+    }
+}
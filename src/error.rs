@@ -1,6 +1,8 @@
 use std::fmt;
 use std::error;
+use std::time::Duration;
 use async_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use serde::Deserialize;
 
 pub type Result<T> = std::result::Result<T, Error>;
 pub(crate) type BoxError = Box<dyn error::Error + Send + Sync>;
@@ -13,7 +15,16 @@ pub(super) enum Kind {
     Tungstenite,
     SerdeJson,
     Hmac,
+    #[cfg(feature = "asymmetric-keys")]
+    Openssl,
     Url,
+    Validation,
+    RateLimit,
+    Credentials,
+    Config,
+    Server,
+    UnknownExecution,
+    OrderBook,
 }
 
 #[derive(Debug)]
@@ -26,6 +37,10 @@ impl WsCloseError {
     pub(super) fn new<T: Into<String>>(code: CloseCode, reason: T) -> Self {
         WsCloseError { code, reason: reason.into() }
     }
+    /// The close code sent by the server.
+    pub fn code(&self) -> CloseCode {
+        self.code
+    }
 }
 
 impl fmt::Display for WsCloseError {
@@ -40,15 +55,427 @@ impl error::Error for WsCloseError {
     }
 }
 
+/// An order that does not satisfy the trading rules of its symbol.
+#[derive(Debug)]
+pub struct ValidationError {
+    symbol: String,
+    message: String,
+}
+
+impl ValidationError {
+    pub(super) fn new<S: Into<String>, M: Into<String>>(symbol: S, message: M) -> Self {
+        ValidationError { symbol: symbol.into(), message: message.into() }
+    }
+    /// The symbol of the rejected order.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.symbol, self.message)
+    }
+}
+
+impl error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+/// A request refused locally while the IP is banned by Binance.
+#[derive(Debug)]
+pub struct RateLimitError {
+    retry_after: Duration,
+}
+
+impl RateLimitError {
+    pub(super) fn new(retry_after: Duration) -> Self {
+        RateLimitError { retry_after }
+    }
+    /// Time until the ban is lifted.
+    pub fn retry_after(&self) -> Duration {
+        self.retry_after
+    }
+}
+
+impl fmt::Display for RateLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "IP banned; retry after {}s", self.retry_after.as_secs())
+    }
+}
+
+impl error::Error for RateLimitError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+/// A 5xx response; the request may or may not have been executed.
+pub struct ServerError {
+    code: u16,
+    reason: String,
+    message: String,
+    body: Option<ErrorBody>,
+}
+
+impl ServerError {
+    pub(super) fn new<T: Into<String>>(code: u16, reason: T, message: T) -> Self {
+        let message = message.into();
+        let body = serde_json::from_str(&message).ok();
+
+        ServerError { code, reason: reason.into(), message, body }
+    }
+    /// HTTP status code of the response.
+    pub fn status(&self) -> u16 {
+        self.code
+    }
+    /// Canonical reason of the HTTP status.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+    /// Raw body of the response.
+    pub fn body(&self) -> &str {
+        &self.message
+    }
+    /// Binance error code of the body, if it has one.
+    pub fn error_code(&self) -> Option<BinanceErrorCode> {
+        self.body.as_ref().map(|body| BinanceErrorCode::from_code(body.code))
+    }
+}
+
+impl fmt::Display for ServerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.code, self.reason)?;
+
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for ServerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut builder = f.debug_struct("ServerError");
+
+        builder.field("code", &self.code);
+        builder.field("reason", &self.reason);
+
+        if let Some(code) = self.error_code() {
+            builder.field("error_code", &code);
+        }
+
+        builder.finish()
+    }
+}
+
+impl error::Error for ServerError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        None
+    }
+}
+
+/// A 5xx response to a new order; Binance documents its status as UNKNOWN.
+///
+/// Look the order up by `client_order_id` before sending it again,
+/// e.g. with `AccountClient::get_order` or `AccountClient::get_oco_order`
+/// for order lists.
+/// # Example
+///
+/// ```no_run
+/// use tokio_binance::{AccountClient, ID, Side::Buy, BINANCE_US_URL};
+/// use serde_json::Value;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+///
+///     let order = match client.place_limit_order("BNBUSDT", Buy, "20.00".parse()?, "5.00".parse()?, true).json::<Value>().await {
+///         Err(e) => match e.unknown_execution() {
+///             Some(unknown) => client
+///                 .get_order("BNBUSDT", ID::ClientOId(unknown.client_order_id()))
+///                 .json::<Value>()
+///                 .await?,
+///             None => return Err(e.into()),
+///         },
+///         order => order?,
+///     };
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct UnknownExecutionError {
+    client_order_id: String,
+    source: ServerError,
+}
+
+impl UnknownExecutionError {
+    pub(super) fn new<T: Into<String>>(client_order_id: T, source: ServerError) -> Self {
+        UnknownExecutionError { client_order_id: client_order_id.into(), source }
+    }
+    /// The client order id of the order, or of the order list.
+    pub fn client_order_id(&self) -> &str {
+        &self.client_order_id
+    }
+    /// The response of the server.
+    pub fn server_error(&self) -> &ServerError {
+        &self.source
+    }
+}
+
+impl fmt::Display for UnknownExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "execution status of {} unknown: {}", self.client_order_id, self.source)
+    }
+}
+
+impl error::Error for UnknownExecutionError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Error code of a Binance error response.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BinanceErrorCode {
+    // General server or network issues
+    /// -1000: An unknown error occurred while processing the request.
+    Unknown,
+    /// -1001: Internal error; unable to process the request.
+    Disconnected,
+    /// -1002: Not authorized to execute this request.
+    Unauthorized,
+    /// -1003: Too many requests queued or the request weight limit was exceeded.
+    TooManyRequests,
+    /// -1006: Unexpected response from the message bus; execution status unknown.
+    UnexpectedResponse,
+    /// -1007: Timeout waiting for the backend; execution status unknown.
+    Timeout,
+    /// -1008: The server is overloaded.
+    ServerBusy,
+    /// -1014: Unsupported order combination.
+    UnknownOrderComposition,
+    /// -1015: Too many new orders.
+    TooManyOrders,
+    /// -1016: This service is no longer available.
+    ServiceShuttingDown,
+    /// -1020: This operation is not supported.
+    UnsupportedOperation,
+    /// -1021: Timestamp outside of the recvWindow or ahead of the server time.
+    InvalidTimestamp,
+    /// -1022: Signature for this request is not valid.
+    InvalidSignature,
+    // Request issues
+    /// -1100: Illegal characters found in a parameter.
+    IllegalChars,
+    /// -1101: Too many parameters sent for this endpoint.
+    TooManyParameters,
+    /// -1102: A mandatory parameter was not sent, was empty or malformed.
+    MandatoryParamEmptyOrMalformed,
+    /// -1103: An unknown parameter was sent.
+    UnknownParam,
+    /// -1104: Not all sent parameters were read.
+    UnreadParameters,
+    /// -1105: A parameter was empty.
+    ParamEmpty,
+    /// -1106: A parameter was sent when not required.
+    ParamNotRequired,
+    /// -1111: Precision is over the maximum defined for this asset.
+    BadPrecision,
+    /// -1112: No orders on book for symbol.
+    NoDepth,
+    /// -1114: TimeInForce parameter sent when not required.
+    TifNotRequired,
+    /// -1115: Invalid timeInForce.
+    InvalidTif,
+    /// -1116: Invalid orderType.
+    InvalidOrderType,
+    /// -1117: Invalid side.
+    InvalidSide,
+    /// -1118: New client order ID was empty.
+    EmptyNewClientOrderId,
+    /// -1119: Original client order ID was empty.
+    EmptyOrigClientOrderId,
+    /// -1120: Invalid interval.
+    BadInterval,
+    /// -1121: Invalid symbol.
+    BadSymbol,
+    /// -1125: This listenKey does not exist.
+    InvalidListenKey,
+    /// -1127: Lookup interval is too big.
+    MoreThanXxHours,
+    /// -1128: Combination of optional parameters invalid.
+    OptionalParamsBadCombo,
+    /// -1130: Invalid data sent for a parameter.
+    InvalidParameter,
+    /// -1131: recvWindow must be less than 60000.
+    BadRecvWindow,
+    // Order and account issues
+    /// -2010: New order rejected.
+    NewOrderRejected,
+    /// -2011: Cancel rejected.
+    CancelRejected,
+    /// -2013: Order does not exist.
+    NoSuchOrder,
+    /// -2014: API-key format invalid.
+    BadApiKeyFormat,
+    /// -2015: Invalid API-key, IP, or permissions for action.
+    RejectedApiKey,
+    /// -2016: No trading window could be found for the symbol.
+    NoTradingWindow,
+    /// Any code not listed above.
+    Other(i64),
+}
+
+impl BinanceErrorCode {
+    /// The code as sent by Binance.
+    pub fn from_code(code: i64) -> Self {
+        use BinanceErrorCode::*;
+
+        match code {
+            -1000 => Unknown,
+            -1001 => Disconnected,
+            -1002 => Unauthorized,
+            -1003 => TooManyRequests,
+            -1006 => UnexpectedResponse,
+            -1007 => Timeout,
+            -1008 => ServerBusy,
+            -1014 => UnknownOrderComposition,
+            -1015 => TooManyOrders,
+            -1016 => ServiceShuttingDown,
+            -1020 => UnsupportedOperation,
+            -1021 => InvalidTimestamp,
+            -1022 => InvalidSignature,
+            -1100 => IllegalChars,
+            -1101 => TooManyParameters,
+            -1102 => MandatoryParamEmptyOrMalformed,
+            -1103 => UnknownParam,
+            -1104 => UnreadParameters,
+            -1105 => ParamEmpty,
+            -1106 => ParamNotRequired,
+            -1111 => BadPrecision,
+            -1112 => NoDepth,
+            -1114 => TifNotRequired,
+            -1115 => InvalidTif,
+            -1116 => InvalidOrderType,
+            -1117 => InvalidSide,
+            -1118 => EmptyNewClientOrderId,
+            -1119 => EmptyOrigClientOrderId,
+            -1120 => BadInterval,
+            -1121 => BadSymbol,
+            -1125 => InvalidListenKey,
+            -1127 => MoreThanXxHours,
+            -1128 => OptionalParamsBadCombo,
+            -1130 => InvalidParameter,
+            -1131 => BadRecvWindow,
+            -2010 => NewOrderRejected,
+            -2011 => CancelRejected,
+            -2013 => NoSuchOrder,
+            -2014 => BadApiKeyFormat,
+            -2015 => RejectedApiKey,
+            -2016 => NoTradingWindow,
+            code => Other(code),
+        }
+    }
+    /// The numeric code, e.g. -1021.
+    pub fn code(self) -> i64 {
+        use BinanceErrorCode::*;
+
+        match self {
+            Unknown => -1000,
+            Disconnected => -1001,
+            Unauthorized => -1002,
+            TooManyRequests => -1003,
+            UnexpectedResponse => -1006,
+            Timeout => -1007,
+            ServerBusy => -1008,
+            UnknownOrderComposition => -1014,
+            TooManyOrders => -1015,
+            ServiceShuttingDown => -1016,
+            UnsupportedOperation => -1020,
+            InvalidTimestamp => -1021,
+            InvalidSignature => -1022,
+            IllegalChars => -1100,
+            TooManyParameters => -1101,
+            MandatoryParamEmptyOrMalformed => -1102,
+            UnknownParam => -1103,
+            UnreadParameters => -1104,
+            ParamEmpty => -1105,
+            ParamNotRequired => -1106,
+            BadPrecision => -1111,
+            NoDepth => -1112,
+            TifNotRequired => -1114,
+            InvalidTif => -1115,
+            InvalidOrderType => -1116,
+            InvalidSide => -1117,
+            EmptyNewClientOrderId => -1118,
+            EmptyOrigClientOrderId => -1119,
+            BadInterval => -1120,
+            BadSymbol => -1121,
+            InvalidListenKey => -1125,
+            MoreThanXxHours => -1127,
+            OptionalParamsBadCombo => -1128,
+            InvalidParameter => -1130,
+            BadRecvWindow => -1131,
+            NewOrderRejected => -2010,
+            CancelRejected => -2011,
+            NoSuchOrder => -2013,
+            BadApiKeyFormat => -2014,
+            RejectedApiKey => -2015,
+            NoTradingWindow => -2016,
+            Other(code) => code,
+        }
+    }
+}
+
+impl fmt::Display for BinanceErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Body of a Binance error response.
+#[derive(Deserialize)]
+struct ErrorBody {
+    code: i64,
+    msg: String,
+}
+
 pub struct ClientError {
     code: u16,
     reason: String,
-    message: String
+    message: String,
+    body: Option<ErrorBody>,
 }
 
 impl ClientError {
     pub(super) fn new<T: Into<String>>(code: u16, reason: T, message: T) -> Self {
-        ClientError { code, reason: reason.into(), message: message.into() }
+        let message = message.into();
+        let body = serde_json::from_str(&message).ok();
+
+        ClientError { code, reason: reason.into(), message, body }
+    }
+    /// HTTP status code of the response.
+    pub fn status(&self) -> u16 {
+        self.code
+    }
+    /// Canonical reason of the HTTP status.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+    /// Raw body of the response.
+    pub fn body(&self) -> &str {
+        &self.message
+    }
+    /// Binance error code of the body, if it has one.
+    pub fn error_code(&self) -> Option<BinanceErrorCode> {
+        self.body.as_ref().map(|body| BinanceErrorCode::from_code(body.code))
+    }
+    /// Binance error message of the body, if it has one.
+    pub fn error_msg(&self) -> Option<&str> {
+        self.body.as_ref().map(|body| body.msg.as_str())
     }
 }
 
@@ -64,6 +491,11 @@ impl fmt::Debug for ClientError {
 
         builder.field("code", &self.code);
         builder.field("reason", &self.reason);
+
+        if let Some(code) = self.error_code() {
+            builder.field("error_code", &code);
+        }
+
         builder.finish()
     }
 }
@@ -91,6 +523,99 @@ impl Error {
     }
 }
 
+impl Error {
+    /// The error response of Binance, if this error is one.
+    pub fn client_error(&self) -> Option<&ClientError> {
+        self.source.as_ref()?.downcast_ref::<ClientError>()
+    }
+    /// Binance error code of the response, if any.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{AccountClient, BINANCE_US_URL};
+    /// use tokio_binance::{Side::Buy, error::BinanceErrorCode};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+    /// match client.place_limit_order("BNBUSDT", Buy, "20.00".parse()?, "5.00".parse()?, true).result().await {
+    ///     Ok(order) => println!("{:?}", order.status),
+    ///     Err(e) if e.is_insufficient_balance() => println!("not enough funds"),
+    ///     Err(e) if e.error_code() == Some(BinanceErrorCode::BadSymbol) => println!("unknown symbol"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn error_code(&self) -> Option<BinanceErrorCode> {
+        match self.client_error() {
+            Some(error) => error.error_code(),
+            None => self.server_error()?.error_code(),
+        }
+    }
+    /// The 5xx response of the server, if this error is one.
+    pub fn server_error(&self) -> Option<&ServerError> {
+        let source = self.source.as_ref()?;
+
+        source.downcast_ref::<ServerError>().or_else(|| {
+            source.downcast_ref::<UnknownExecutionError>().map(UnknownExecutionError::server_error)
+        })
+    }
+    /// The new order whose status is unknown after a 5xx response, if this error is one.
+    pub fn unknown_execution(&self) -> Option<&UnknownExecutionError> {
+        self.source.as_ref()?.downcast_ref::<UnknownExecutionError>()
+    }
+    /// Whether the request was refused because of rate limits, including IP bans.
+    pub fn is_rate_limited(&self) -> bool {
+        if let Kind::RateLimit = self.kind {
+            return true;
+        }
+
+        match self.client_error() {
+            Some(error) => {
+                error.status() == 429
+                    || error.status() == 418
+                    || matches!(error.error_code(), Some(BinanceErrorCode::TooManyRequests) | Some(BinanceErrorCode::TooManyOrders))
+            }
+            None => false,
+        }
+    }
+    /// Whether the IP is banned, either by a 418 response or by the rate limiter
+    /// refusing requests until the ban is lifted.
+    pub fn is_banned(&self) -> bool {
+        if let Kind::RateLimit = self.kind {
+            return true;
+        }
+
+        self.client_error().is_some_and(|error| error.status() == 418)
+    }
+    /// Whether the same request may succeed when sent again, possibly after a delay.
+    ///
+    /// Requests refused while the IP is banned aren't; sending them only extends the ban.
+    /// Orders failing with an unknown execution status may have been placed;
+    /// check them before sending them again.
+    pub fn is_retryable(&self) -> bool {
+        use BinanceErrorCode::*;
+
+        if let Some(error) = self.source.as_ref().and_then(|e| e.downcast_ref::<reqwest::Error>()) {
+            return error.is_timeout() || error.is_connect();
+        }
+
+        (self.is_rate_limited() && !self.is_banned())
+            || self.server_error().is_some()
+            || matches!(
+                self.error_code(),
+                Some(Unknown) | Some(Disconnected) | Some(UnexpectedResponse) | Some(Timeout) | Some(ServerBusy) | Some(InvalidTimestamp)
+            )
+    }
+    /// Whether an order was rejected for lack of funds.
+    pub fn is_insufficient_balance(&self) -> bool {
+        self.client_error()
+            .and_then(ClientError::error_msg)
+            .is_some_and(|msg| msg.to_lowercase().contains("insufficient balance"))
+    }
+}
+
 impl fmt::Debug for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut builder = f.debug_struct("tokio-binance::Error");
@@ -133,6 +658,30 @@ impl From<WsCloseError> for Error {
     }
 }
 
+impl From<ValidationError> for Error {
+    fn from(error: ValidationError) -> Self {
+        Error::new(Kind::Validation, Some(error))
+    }
+}
+
+impl From<RateLimitError> for Error {
+    fn from(error: RateLimitError) -> Self {
+        Error::new(Kind::RateLimit, Some(error))
+    }
+}
+
+impl From<ServerError> for Error {
+    fn from(error: ServerError) -> Self {
+        Error::new(Kind::Server, Some(error))
+    }
+}
+
+impl From<UnknownExecutionError> for Error {
+    fn from(error: UnknownExecutionError) -> Self {
+        Error::new(Kind::UnknownExecution, Some(error))
+    }
+}
+
 impl From<reqwest::Error> for Error {
     fn from(error: reqwest::Error) -> Self {
         Error::new(Kind::Reqwest, Some(error))
@@ -167,4 +716,11 @@ impl From<hmac::crypto_mac::InvalidKeyLength> for Error {
     fn from(error: hmac::crypto_mac::InvalidKeyLength) -> Self {
         Error::new(Kind::Hmac, Some(error))
     }
+}
+
+#[cfg(feature = "asymmetric-keys")]
+impl From<openssl::error::ErrorStack> for Error {
+    fn from(error: openssl::error::ErrorStack) -> Self {
+        Error::new(Kind::Openssl, Some(error))
+    }
 }
\ No newline at end of file
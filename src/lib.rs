@@ -21,49 +21,196 @@
 //!     Ok(())
 //! }
 //! ```
+//! ### Shared configuration
+//! [`BinanceClient`] hands out the clients above, sharing one connection pool and configuration.
+//! ```no_run
+//! use tokio_binance::{ApiKey, BinanceClient, Credentials, BINANCE_US_URL};
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let binance = BinanceClient::builder(BINANCE_US_URL)
+//!         .with_credentials(Credentials::new(ApiKey::new("<api-key>", "<secret-key>")))
+//!         .with_timeout(Duration::from_secs(10))
+//!         .with_proxy("http://localhost:8080")
+//!         .build()?;
+//!     let account = binance.to_account_client()?;
+//!     let market = binance.to_market_data_client()?;
+//!     Ok(())
+//! }
+//! ```
 //! ### Websocket
 //! ```no_run
 //! use tokio_binance::*;
-//! use tokio::time::Duration;
-//! use serde_json::Value;
+//! use tokio_binance::model::{Event, UserDataEvent};
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     let client = UserDataClient::connect("<api-key>", BINANCE_US_URL)?;
-//!     let value = client.start_stream().json::<Value>().await?;
+//!     // keeps the listen key alive and reconnects when it expires.
+//!     let mut stream = UserDataStream::connect(client, BINANCE_US_WSS_URL).await?;
 //!
-//!     let listen_key = value["listenKey"].as_str().unwrap();
-//!     let listen_key_copy = listen_key.to_string();
-//!
-//!     tokio::spawn(async move {
-//!         loop {
-//!             std::thread::sleep(Duration::from_secs(30*60));
-//!             if let Err(e) = client.keep_alive(&listen_key_copy).text().await {
-//!                 eprintln!("{}", e);
-//!                 return
-//!             }
+//!     while let Some(event) = stream.event().await? {
+//!         if let Event::UserData(UserDataEvent::ExecutionReport(report)) = event {
+//!             println!("{} {:?}", report.client_order_id, report.order_status);
 //!         }
-//!     });
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//! ### WebSocket API
+//! ```no_run
+//! use tokio_binance::{WsApiClient, BINANCE_US_WS_API_URL, Side, ID};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = WsApiClient::connect("<api-key>", "<secret-key>", BINANCE_US_WS_API_URL).await?;
+//!     let order = client
+//!         .place_limit_order("BNBUSDT", Side::Buy, "20.10".parse()?, "5.00".parse()?, true)
+//!         .result()
+//!         .await?;
+//!     client.cancel_order("BNBUSDT", ID::OrderId(order.order_id)).canceled_order().await?;
+//!     // usage of the rate limits, as reported by the last response.
+//!     println!("{:?}", client.rate_limits());
+//!     Ok(())
+//! }
+//! ```
+//! ### Margin
+//! ```no_run
+//! use tokio_binance::{MarginClient, Environment, Side, SideEffectType};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = MarginClient::connect("<api-key>", "<secret-key>", Environment::Binance)?;
+//!     let max = client.get_max_borrowable("USDT").amount().await?;
+//!     let order = client
+//!         .place_market_order("BNBUSDT", Side::Buy, "1.00".parse()?)
+//!         .with_side_effect_type(SideEffectType::MarginBuy)
+//!         .full()
+//!         .await?;
+//!     let account = client.get_account().account().await?;
+//!     println!("margin level {}", account.margin_level);
+//!     Ok(())
+//! }
+//! ```
+//! ### Futures
+//! ```no_run
+//! use tokio_binance::{FuturesClient, Environment, Side, MarginType};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+//!     client.change_margin_type("BTCUSDT", MarginType::Isolated).text().await?;
+//!     client.change_leverage("BTCUSDT", 5).leverage().await?;
+//!     let order = client
+//!         .place_market_order("BTCUSDT", Side::Buy, "0.01".parse()?, true)
+//!         .order()
+//!         .await?;
+//!     for position in client.get_position_risk().with_symbol("BTCUSDT").positions().await? {
+//!         println!("{} {}", position.position_amt, position.unrealized_profit);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//! COIN-M contracts are sized in whole contracts with [`DeliveryClient`]:
+//! ```no_run
+//! use tokio_binance::{DeliveryClient, Environment, Interval, Side};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = DeliveryClient::connect("<api-key>", "<secret-key>", Environment::CoinMFutures)?;
+//!     let info = client.get_exchange_info().exchange_info().await?;
+//!     let quarterly = info.symbols.iter().find(|s| s.contract_type == "CURRENT_QUARTER").unwrap();
+//!     let order = client
+//!         .place_market_order(&quarterly.symbol, Side::Buy, 10, true)
+//!         .order()
+//!         .await?;
+//!     let marks = client.get_mark_price_klines(&quarterly.symbol, Interval::OneHour).klines().await?;
+//!     Ok(())
+//! }
+//! ```
+//! Futures market streams go through the same [`WebSocketStream`]:
+//! ```no_run
+//! use tokio_binance::{Channel, Environment, WebSocketStream};
+//! use tokio_binance::model::Event;
 //!
-//!     let channel = Channel::UserData(listen_key);
-//!     let mut stream = WebSocketStream::connect(channel, BINANCE_US_WSS_URL).await?;
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let channel = Channel::MarkPrice("BTCUSDT");
+//!     let mut stream = WebSocketStream::connect(channel, Environment::UsdMFutures).await?;
+//!     stream.subscribe(&[Channel::AllLiquidationOrders]).await?;
 //!
-//!     while let Some(value) = stream.json::<Value>().await? {
-//!         if channel == value["stream"] {
-//!             println!("{}", serde_json::to_string_pretty(&value)?);
+//!     while let Some((_, event)) = stream.event().await? {
+//!         match event {
+//!             Event::MarkPrice(mark) => println!("mark {} funding {:?}", mark.mark_price, mark.funding_rate),
+//!             Event::LiquidationOrder(liquidation) => println!("{:?}", liquidation.order),
+//!             _ => (),
 //!         }
 //!     }
 //!     Ok(())
 //! }
 //! ```
+//! ### Decimals
+//! Prices and quantities are [`Number`]s, which are `f64` by default or
+//! `rust_decimal::Decimal` with the `decimal` feature enabled.
+//! ```no_run
+//! use tokio_binance::{AccountClient, BINANCE_US_URL, Number, Side};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+//!     // `Number` is rust_decimal::Decimal with the `decimal` feature; sent as written.
+//!     let price: Number = "20.10".parse()?;
+//!     let quantity: Number = "5.00".parse()?;
+//!     let order = client
+//!         .place_limit_order("BNBUSDT", Side::Buy, price, quantity, true)
+//!         .result()
+//!         .await?;
+//!     println!("{}", order.executed_qty);
+//!     Ok(())
+//! }
+//! ```
+//! ### Signing
+//! Requests are signed with HMAC-SHA256 by `connect`. RSA and Ed25519 API keys
+//! are supported with the `asymmetric-keys` feature enabled; pass a [`Signer`]
+//! to `connect_with_signer` instead of the secret key.
+//! ```ignore
+//! use tokio_binance::{AccountClient, BINANCE_US_URL, Ed25519Signer};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let signer = Ed25519Signer::from_pem(&std::fs::read("private_key.pem")?)?;
+//!     let client = AccountClient::connect_with_signer("<api-key>", signer, BINANCE_US_URL)?;
+//!     Ok(())
+//! }
+//! ```
 
 pub mod builder;
 mod client;
+mod credentials;
+mod environment;
 pub mod error;
+pub mod model;
+mod order_book;
 mod param;
+mod rate_limit;
+mod retry;
+mod rules;
+mod signer;
+mod time_sync;
 pub mod types;
+mod user_stream;
 mod ws_stream;
 
 pub use self::client::*;
+pub use self::credentials::*;
+pub use self::environment::*;
+pub use self::order_book::*;
 pub use self::param::*;
+pub use self::rate_limit::*;
+pub use self::retry::RetryPolicy;
+pub use self::rules::*;
+pub use self::signer::*;
+pub use self::time_sync::*;
+pub use self::user_stream::*;
 pub use self::ws_stream::*;
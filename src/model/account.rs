@@ -0,0 +1,303 @@
+use serde::Deserialize;
+use crate::param::{Number, OrderType, Side, TimeInForce};
+use super::de;
+
+fn no_order_list() -> i64 {
+    -1
+}
+
+/// Current status of an order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    PendingCancel,
+    Rejected,
+    Expired,
+    ExpiredInMatch,
+    /// Futures only: liquidation with the insurance fund.
+    NewInsurance,
+    /// Futures only: counterparty liquidation through auto-deleveraging.
+    NewAdl,
+}
+
+/// Order response when `OrderRespType::Ack` is requested.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAck {
+    pub symbol: String,
+    pub order_id: i64,
+    /// -1 unless the order is part of an order list; margin orders leave it out.
+    #[serde(default = "no_order_list")]
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    /// `time` when the order was looked up after a retry.
+    #[serde(alias = "time")]
+    pub transact_time: i64,
+}
+
+/// Order response when `OrderRespType::Result` is requested.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResult {
+    pub symbol: String,
+    pub order_id: i64,
+    /// -1 unless the order is part of an order list; margin orders leave it out.
+    #[serde(default = "no_order_list")]
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    /// `time` when the order was looked up after a retry.
+    #[serde(alias = "time")]
+    pub transact_time: i64,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub orig_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub executed_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub cummulative_quote_qty: Number,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: Side,
+}
+
+/// Order response when `OrderRespType::Full` is requested.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFull {
+    pub symbol: String,
+    pub order_id: i64,
+    /// -1 unless the order is part of an order list; margin orders leave it out.
+    #[serde(default = "no_order_list")]
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    /// `time` when the order was looked up after a retry.
+    #[serde(alias = "time")]
+    pub transact_time: i64,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub orig_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub executed_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub cummulative_quote_qty: Number,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: Side,
+    /// Empty when the order was looked up after a retry.
+    #[serde(default)]
+    pub fills: Vec<Fill>,
+}
+
+/// A partial or complete fill of an order.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fill {
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub commission: Number,
+    pub commission_asset: String,
+    #[serde(default)]
+    pub trade_id: i64,
+}
+
+/// Response of `AccountClient::get_order`, `get_open_orders` and `get_all_orders`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub symbol: String,
+    pub order_id: i64,
+    /// -1 unless the order is part of an order list; margin orders leave it out.
+    #[serde(default = "no_order_list")]
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub orig_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub executed_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub cummulative_quote_qty: Number,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: Side,
+    #[serde(deserialize_with = "de::number")]
+    pub stop_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub iceberg_qty: Number,
+    pub time: i64,
+    pub update_time: i64,
+    pub is_working: bool,
+    #[serde(default, deserialize_with = "de::number")]
+    pub orig_quote_order_qty: Number,
+}
+
+/// Response of `AccountClient::cancel_order`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledOrder {
+    pub symbol: String,
+    pub orig_client_order_id: String,
+    pub order_id: i64,
+    /// -1 unless the order is part of an order list; margin orders leave it out.
+    #[serde(default = "no_order_list")]
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub orig_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub executed_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub cummulative_quote_qty: Number,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: Side,
+}
+
+/// Asset balance of an account.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Balance {
+    pub asset: String,
+    #[serde(deserialize_with = "de::number")]
+    pub free: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub locked: Number,
+}
+
+/// Response of `AccountClient::get_account`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    pub maker_commission: i64,
+    pub taker_commission: i64,
+    pub buyer_commission: i64,
+    pub seller_commission: i64,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub can_deposit: bool,
+    pub update_time: i64,
+    #[serde(default)]
+    pub account_type: String,
+    pub balances: Vec<Balance>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
+
+/// Response item of `AccountClient::get_account_trades`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MyTrade {
+    pub symbol: String,
+    pub id: i64,
+    pub order_id: i64,
+    #[serde(default = "no_order_list")]
+    pub order_list_id: i64,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub quote_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub commission: Number,
+    pub commission_asset: String,
+    pub time: i64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_best_match: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn decodes_order_responses() {
+        let order: OrderFull = serde_json::from_str(r#"{
+            "symbol": "BTCUSDT", "orderId": 28, "orderListId": -1,
+            "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP", "transactTime": 1507725176595,
+            "price": "0.00000000", "origQty": "10.00000000", "executedQty": "10.00000000",
+            "cummulativeQuoteQty": "10.00000000", "status": "FILLED", "timeInForce": "GTC",
+            "type": "MARKET", "side": "SELL",
+            "fills": [
+                { "price": "4000.00000000", "qty": "1.00000000", "commission": "4.00000000", "commissionAsset": "USDT", "tradeId": 56 },
+                { "price": "3999.00000000", "qty": "5.00000000", "commission": "19.99500000", "commissionAsset": "USDT", "tradeId": 57 }
+            ]
+        }"#).unwrap();
+
+        assert_eq!((order.status, order.order_type, order.side), (OrderStatus::Filled, OrderType::Market, Side::Sell));
+        assert_eq!(order.fills.len(), 2);
+        assert_eq!(order.fills[1].commission, number("19.995"));
+
+        // Margin orders leave out the order list id.
+        let ack: OrderAck = serde_json::from_str(r#"{
+            "symbol": "BTCUSDT", "orderId": 28, "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP", "transactTime": 1507725176595
+        }"#).unwrap();
+        assert_eq!(ack.order_list_id, -1);
+    }
+
+    #[test]
+    fn decodes_looked_up_orders() {
+        let order: Order = serde_json::from_str(r#"{
+            "symbol": "LTCBTC", "orderId": 1, "orderListId": -1, "clientOrderId": "myOrder1",
+            "price": "0.1", "origQty": "1.0", "executedQty": "0.0", "cummulativeQuoteQty": "0.0",
+            "status": "NEW", "timeInForce": "GTC", "type": "LIMIT", "side": "BUY",
+            "stopPrice": "0.0", "icebergQty": "0.0", "time": 1499827319559, "updateTime": 1499827319559,
+            "isWorking": true, "origQuoteOrderQty": "0.000000"
+        }"#).unwrap();
+        assert_eq!((order.price, order.time), (number("0.1"), 1499827319559));
+
+        // An order looked up after a retry stands in for the placement response.
+        let result: OrderResult = serde_json::from_str(r#"{
+            "symbol": "LTCBTC", "orderId": 1, "orderListId": -1, "clientOrderId": "myOrder1",
+            "price": "0.1", "origQty": "1.0", "executedQty": "0.0", "cummulativeQuoteQty": "0.0",
+            "status": "NEW", "timeInForce": "GTC", "type": "LIMIT", "side": "BUY",
+            "time": 1499827319559
+        }"#).unwrap();
+        assert_eq!(result.transact_time, 1499827319559);
+    }
+
+    #[test]
+    fn decodes_accounts_and_trades() {
+        let account: AccountInfo = serde_json::from_str(r#"{
+            "makerCommission": 15, "takerCommission": 15, "buyerCommission": 0, "sellerCommission": 0,
+            "canTrade": true, "canWithdraw": true, "canDeposit": true, "updateTime": 123456789,
+            "accountType": "SPOT",
+            "balances": [{ "asset": "BTC", "free": "4723846.89208129", "locked": "0.00000000" }],
+            "permissions": ["SPOT"]
+        }"#).unwrap();
+        assert_eq!(account.balances[0].free, number("4723846.89208129"));
+
+        let trade: MyTrade = serde_json::from_str(r#"{
+            "symbol": "BNBBTC", "id": 28457, "orderId": 100234, "price": "4.00000100",
+            "qty": "12.00000000", "quoteQty": "48.000012", "commission": "10.10000000",
+            "commissionAsset": "BNB", "time": 1499865549590, "isBuyer": true, "isMaker": false,
+            "isBestMatch": true
+        }"#).unwrap();
+        assert_eq!(trade.order_list_id, -1);
+        assert_eq!(trade.commission, number("10.1"));
+    }
+}
@@ -0,0 +1,82 @@
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use std::fmt;
+use crate::param::Number;
+
+/// Deserializes a number that Binance may send either quoted or bare.
+pub(crate) fn number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Number, D::Error> {
+    deserializer.deserialize_any(NumberVisitor)
+}
+
+/// Deserializes a number that Binance sends as an empty string or null when it doesn't apply.
+pub(crate) fn optional_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Number>, D::Error> {
+    deserializer.deserialize_any(OptionalNumberVisitor)
+}
+
+/// Newtype used where a quoted number sits inside a positional array.
+pub(crate) struct Quoted(pub Number);
+
+impl<'de> Deserialize<'de> for Quoted {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        number(deserializer).map(Quoted)
+    }
+}
+
+struct NumberVisitor;
+
+impl<'de> Visitor<'de> for NumberVisitor {
+    type Value = Number;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a number or a string containing a number")
+    }
+
+    // Bare numbers go through their textual form so both number types parse them the same way.
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Number, E> {
+        self.visit_str(&value.to_string())
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Number, E> {
+        self.visit_str(&value.to_string())
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Number, E> {
+        self.visit_str(&value.to_string())
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Number, E> {
+        value.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+struct OptionalNumberVisitor;
+
+impl<'de> Visitor<'de> for OptionalNumberVisitor {
+    type Value = Option<Number>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a number, a string containing a number, an empty string or null")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Option<Number>, E> {
+        Ok(None)
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Option<Number>, E> {
+        NumberVisitor.visit_f64(value).map(Some)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Option<Number>, E> {
+        NumberVisitor.visit_i64(value).map(Some)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Option<Number>, E> {
+        NumberVisitor.visit_u64(value).map(Some)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Option<Number>, E> {
+        match value {
+            "" => Ok(None),
+            value => NumberVisitor.visit_str(value).map(Some),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use serde::Deserialize;
+use crate::param::{Number, OrderType, PositionSide, Side, TimeInForce, WorkingType};
+use super::de;
+use super::{OrderStatus, RateLimit};
+
+/// Response of `FuturesClient::place_limit_order`, `place_market_order`, `get_order` and `cancel_order`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesOrder {
+    pub symbol: String,
+    pub order_id: i64,
+    pub client_order_id: String,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub avg_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub orig_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub executed_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub cum_quote: Number,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub orig_type: OrderType,
+    pub side: Side,
+    pub position_side: PositionSide,
+    #[serde(deserialize_with = "de::number")]
+    pub stop_price: Number,
+    pub reduce_only: bool,
+    pub close_position: bool,
+    pub working_type: WorkingType,
+    pub update_time: i64,
+}
+
+/// Position of a symbol, from `FuturesClient::get_position_risk` or `DeliveryClient::get_position_info`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: String,
+    pub position_side: PositionSide,
+    /// Negative for short positions in one-way mode.
+    #[serde(deserialize_with = "de::number")]
+    pub position_amt: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub entry_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub mark_price: Number,
+    #[serde(rename = "unRealizedProfit", deserialize_with = "de::number")]
+    pub unrealized_profit: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub liquidation_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub leverage: Number,
+    /// `isolated` or `cross`.
+    pub margin_type: String,
+    #[serde(deserialize_with = "de::number")]
+    pub isolated_margin: Number,
+    pub update_time: i64,
+}
+
+/// Response of `FuturesClient::change_leverage`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Leverage {
+    pub symbol: String,
+    pub leverage: u32,
+    #[serde(deserialize_with = "de::number")]
+    pub max_notional_value: Number,
+}
+
+/// Balance of an asset, from `FuturesClient::get_balance`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesBalance {
+    pub asset: String,
+    #[serde(deserialize_with = "de::number")]
+    pub balance: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub cross_wallet_balance: Number,
+    #[serde(rename = "crossUnPnl", deserialize_with = "de::number")]
+    pub cross_unrealized_pnl: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub available_balance: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub max_withdraw_amount: Number,
+    pub update_time: i64,
+}
+
+/// Entry of `FuturesClient::get_income_history`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// Empty for transfers.
+    pub symbol: String,
+    /// `IncomeType` as sent by Binance, which has more kinds than can be filtered on.
+    pub income_type: String,
+    #[serde(deserialize_with = "de::number")]
+    pub income: Number,
+    pub asset: String,
+    pub info: String,
+    pub time: i64,
+    pub tran_id: i64,
+    /// Empty unless the income comes from a trade.
+    pub trade_id: String,
+}
+
+/// Entry of `FuturesClient::get_funding_rate_history`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(deserialize_with = "de::number")]
+    pub funding_rate: Number,
+    pub funding_time: i64,
+}
+
+/// Response of `DeliveryClient::place_limit_order`, `place_market_order`, `get_order` and `cancel_order`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryOrder {
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: i64,
+    pub client_order_id: String,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub avg_price: Number,
+    /// Quantity in contracts.
+    #[serde(deserialize_with = "de::number")]
+    pub orig_qty: Number,
+    /// Filled contracts.
+    #[serde(deserialize_with = "de::number")]
+    pub executed_qty: Number,
+    /// Filled quantity in the base asset.
+    #[serde(deserialize_with = "de::number")]
+    pub cum_base: Number,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub orig_type: OrderType,
+    pub side: Side,
+    pub position_side: PositionSide,
+    #[serde(deserialize_with = "de::number")]
+    pub stop_price: Number,
+    pub reduce_only: bool,
+    pub close_position: bool,
+    pub working_type: WorkingType,
+    pub update_time: i64,
+}
+
+/// Response of `DeliveryClient::get_exchange_info`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryExchangeInfo {
+    pub timezone: String,
+    pub server_time: i64,
+    pub rate_limits: Vec<RateLimit>,
+    pub symbols: Vec<DeliverySymbol>,
+}
+
+/// Delivery or perpetual contract of `DeliveryExchangeInfo`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliverySymbol {
+    pub symbol: String,
+    pub pair: String,
+    /// `PERPETUAL`, `CURRENT_QUARTER` or `NEXT_QUARTER`.
+    pub contract_type: String,
+    pub contract_status: String,
+    /// Far in the future for perpetual contracts.
+    pub delivery_date: i64,
+    pub onboard_date: i64,
+    /// Value of one contract in the quote asset.
+    pub contract_size: u32,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub margin_asset: String,
+    pub price_precision: u32,
+    pub quantity_precision: u32,
+}
+
+/// Leverage brackets of a symbol, from `DeliveryClient::get_leverage_brackets`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBrackets {
+    pub symbol: String,
+    pub brackets: Vec<LeverageBracket>,
+}
+
+/// Highest leverage allowed for positions of up to `qty_cap`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracket {
+    pub bracket: u32,
+    pub initial_leverage: u32,
+    /// Position size in the base asset where the bracket ends.
+    #[serde(deserialize_with = "de::number")]
+    pub qty_cap: Number,
+    #[serde(rename = "qtylFloor", deserialize_with = "de::number")]
+    pub qty_floor: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub maint_margin_ratio: Number,
+}
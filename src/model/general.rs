@@ -0,0 +1,188 @@
+use serde::Deserialize;
+use crate::param::{Number, OrderType};
+use super::de;
+
+/// Response of `GeneralClient::get_server_time`.
+#[derive(Copy, Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerTime {
+    pub server_time: i64,
+}
+
+/// Kind of limit described by a [`RateLimit`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RateLimitType {
+    RequestWeight,
+    Orders,
+    RawRequests,
+}
+
+/// Unit of the interval of a [`RateLimit`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RateLimitInterval {
+    Second,
+    Minute,
+    Day,
+}
+
+/// Limit on the requests or orders sent within an interval.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimit {
+    pub rate_limit_type: RateLimitType,
+    pub interval: RateLimitInterval,
+    pub interval_num: u32,
+    pub limit: u32,
+}
+
+/// Usage of a limit, as reported by a WebSocket API response.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitUsage {
+    pub rate_limit_type: RateLimitType,
+    pub interval: RateLimitInterval,
+    pub interval_num: u32,
+    pub limit: u32,
+    pub count: u32,
+}
+
+/// Trading rule of a symbol, tagged by its `filterType`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SymbolFilter {
+    #[serde(rename_all = "camelCase")]
+    PriceFilter {
+        #[serde(deserialize_with = "de::number")]
+        min_price: Number,
+        #[serde(deserialize_with = "de::number")]
+        max_price: Number,
+        #[serde(deserialize_with = "de::number")]
+        tick_size: Number,
+    },
+    #[serde(rename_all = "camelCase")]
+    PercentPrice {
+        #[serde(deserialize_with = "de::number")]
+        multiplier_up: Number,
+        #[serde(deserialize_with = "de::number")]
+        multiplier_down: Number,
+        avg_price_mins: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    LotSize {
+        #[serde(deserialize_with = "de::number")]
+        min_qty: Number,
+        #[serde(deserialize_with = "de::number")]
+        max_qty: Number,
+        #[serde(deserialize_with = "de::number")]
+        step_size: Number,
+    },
+    #[serde(rename_all = "camelCase")]
+    MarketLotSize {
+        #[serde(deserialize_with = "de::number")]
+        min_qty: Number,
+        #[serde(deserialize_with = "de::number")]
+        max_qty: Number,
+        #[serde(deserialize_with = "de::number")]
+        step_size: Number,
+    },
+    #[serde(rename_all = "camelCase")]
+    MinNotional {
+        #[serde(deserialize_with = "de::number")]
+        min_notional: Number,
+        #[serde(default)]
+        apply_to_market: bool,
+        #[serde(default)]
+        avg_price_mins: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    Notional {
+        #[serde(deserialize_with = "de::number")]
+        min_notional: Number,
+        apply_min_to_market: bool,
+        #[serde(deserialize_with = "de::number")]
+        max_notional: Number,
+        apply_max_to_market: bool,
+        avg_price_mins: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    IcebergParts {
+        limit: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    MaxNumOrders {
+        max_num_orders: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    MaxNumAlgoOrders {
+        max_num_algo_orders: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    MaxNumIcebergOrders {
+        max_num_iceberg_orders: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    MaxPosition {
+        #[serde(deserialize_with = "de::number")]
+        max_position: Number,
+    },
+    /// Filter not known to this crate.
+    #[serde(other)]
+    Other,
+}
+
+/// Trading rules and status of a symbol.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolInfo {
+    pub symbol: String,
+    pub status: String,
+    pub base_asset: String,
+    pub base_asset_precision: u32,
+    pub quote_asset: String,
+    pub quote_precision: u32,
+    pub order_types: Vec<OrderType>,
+    pub iceberg_allowed: bool,
+    #[serde(default)]
+    pub oco_allowed: bool,
+    #[serde(default)]
+    pub is_spot_trading_allowed: bool,
+    #[serde(default)]
+    pub is_margin_trading_allowed: bool,
+    pub filters: Vec<SymbolFilter>,
+}
+
+/// Response of `GeneralClient::get_exchange_info`.
+/// # Example
+///
+/// ```
+/// use tokio_binance::model::{ExchangeInfo, SymbolFilter};
+///
+/// let info: ExchangeInfo = serde_json::from_str(r#"{
+///     "timezone": "UTC", "serverTime": 1565246363776, "rateLimits": [],
+///     "symbols": [{
+///         "symbol": "BNBUSDT", "status": "TRADING",
+///         "baseAsset": "BNB", "baseAssetPrecision": 8,
+///         "quoteAsset": "USDT", "quotePrecision": 8,
+///         "orderTypes": ["LIMIT", "MARKET"], "icebergAllowed": true,
+///         "filters": [{
+///             "filterType": "LOT_SIZE", "minQty": "0.01000000",
+///             "maxQty": "9000000.00000000", "stepSize": "0.01000000"
+///         }, {
+///             "filterType": "TRAILING_DELTA", "minTrailingAboveDelta": 10
+///         }]
+///     }]
+/// }"#).unwrap();
+///
+/// assert!(matches!(info.symbols[0].filters[0], SymbolFilter::LotSize { .. }));
+/// assert!(matches!(info.symbols[0].filters[1], SymbolFilter::Other));
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfo {
+    pub timezone: String,
+    pub server_time: i64,
+    pub rate_limits: Vec<RateLimit>,
+    pub symbols: Vec<SymbolInfo>,
+}
@@ -0,0 +1,169 @@
+use serde::Deserialize;
+use crate::param::{Number, Side, TimeInForce};
+use super::de;
+
+/// Response of `MarginClient::borrow` and `repay`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransaction {
+    pub tran_id: i64,
+}
+
+/// Asset of a [`MarginAccount`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginBalance {
+    pub asset: String,
+    #[serde(deserialize_with = "de::number")]
+    pub borrowed: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub free: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub interest: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub locked: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub net_asset: Number,
+}
+
+/// Response of `MarginClient::get_account`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccount {
+    pub borrow_enabled: bool,
+    /// Assets over liabilities; positions get liquidated at 1.1.
+    #[serde(deserialize_with = "de::number")]
+    pub margin_level: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub total_asset_of_btc: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub total_liability_of_btc: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub total_net_asset_of_btc: Number,
+    pub trade_enabled: bool,
+    pub transfer_enabled: bool,
+    pub user_assets: Vec<MarginBalance>,
+}
+
+/// Base or quote asset of an [`IsolatedMarginPair`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginBalance {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    pub repay_enabled: bool,
+    #[serde(deserialize_with = "de::number")]
+    pub borrowed: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub free: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub interest: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub locked: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub net_asset: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub net_asset_of_btc: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub total_asset: Number,
+}
+
+/// Isolated margin account of a single symbol.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    pub symbol: String,
+    pub base_asset: IsolatedMarginBalance,
+    pub quote_asset: IsolatedMarginBalance,
+    pub isolated_created: bool,
+    pub enabled: bool,
+    pub trade_enabled: bool,
+    #[serde(deserialize_with = "de::number")]
+    pub margin_level: Number,
+    /// `EXCESSIVE`, `NORMAL`, `MARGIN_CALL`, `PRE_LIQUIDATION` or `FORCE_LIQUIDATION`.
+    pub margin_level_status: String,
+    #[serde(deserialize_with = "de::number")]
+    pub margin_ratio: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub index_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub liquidate_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub liquidate_rate: Number,
+}
+
+/// Response of `MarginClient::get_isolated_account`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccount {
+    pub assets: Vec<IsolatedMarginPair>,
+    #[serde(deserialize_with = "de::number")]
+    pub total_asset_of_btc: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub total_liability_of_btc: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub total_net_asset_of_btc: Number,
+}
+
+/// Response of `MarginClient::get_max_borrowable` and `get_max_transferable`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxAmount {
+    #[serde(deserialize_with = "de::number")]
+    pub amount: Number,
+    /// Only sent for borrowing; the limit of the account's VIP level.
+    #[serde(default, deserialize_with = "de::optional_number")]
+    pub borrow_limit: Option<Number>,
+}
+
+/// Page of a margin history.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Rows<T> {
+    /// Left out when the page is empty.
+    #[serde(default = "Vec::new")]
+    pub rows: Vec<T>,
+    /// Entries over all pages.
+    pub total: u64,
+}
+
+/// Entry of `MarginClient::get_interest_history`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestEntry {
+    pub tx_id: i64,
+    #[serde(rename = "interestAccuredTime")]
+    pub interest_accrued_time: i64,
+    pub asset: String,
+    #[serde(deserialize_with = "de::number")]
+    pub principal: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub interest: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub interest_rate: Number,
+    /// `PERIODIC`, `ON_BORROW`, `PERIODIC_CONVERTED` or `ON_BORROW_CONVERTED`.
+    #[serde(rename = "type")]
+    pub interest_type: String,
+    /// Empty for the cross margin account.
+    #[serde(default)]
+    pub isolated_symbol: String,
+}
+
+/// Entry of `MarginClient::get_force_liquidation_history`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidationEntry {
+    pub order_id: i64,
+    pub symbol: String,
+    pub side: Side,
+    pub time_in_force: TimeInForce,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub avg_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub executed_qty: Number,
+    pub is_isolated: bool,
+    pub updated_time: i64,
+}
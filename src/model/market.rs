@@ -0,0 +1,262 @@
+use serde::de::{Deserializer, IgnoredAny};
+use serde::Deserialize;
+use super::de::{self, Quoted};
+use crate::param::Number;
+
+/// A single price level of an order book.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PriceLevel {
+    pub price: Number,
+    pub qty: Number,
+}
+
+impl<'de> Deserialize<'de> for PriceLevel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (Quoted(price), Quoted(qty)) = Deserialize::deserialize(deserializer)?;
+        Ok(PriceLevel { price, qty })
+    }
+}
+
+/// Response of `MarketDataClient::get_order_book`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBook {
+    pub last_update_id: u64,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+}
+
+/// Response item of `MarketDataClient::get_trades` and `get_historical_trades`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub quote_qty: Number,
+    pub time: i64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
+/// Response item of `MarketDataClient::get_aggregate_trades`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AggTrade {
+    #[serde(rename = "a")]
+    pub id: u64,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(rename = "q", deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(rename = "f")]
+    pub first_trade_id: u64,
+    #[serde(rename = "l")]
+    pub last_trade_id: u64,
+    #[serde(rename = "T")]
+    pub time: i64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+    #[serde(rename = "M")]
+    pub is_best_match: bool,
+}
+
+/// Response item of `MarketDataClient::get_candlestick_bars`.
+///
+/// Binance sends klines as positional arrays; this type decodes them by index.
+/// # Example
+///
+/// ```
+/// use tokio_binance::{model::Kline, Number};
+///
+/// let kline: Kline = serde_json::from_str(r#"[
+///     1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100",
+///     "148976.11427815", 1499644799999, "2434.19055334", 308,
+///     "1756.87402397", "28.46694368", "0"
+/// ]"#).unwrap();
+///
+/// assert_eq!(kline.number_of_trades, 308);
+/// assert_eq!(kline.close, "0.015771".parse::<Number>().unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Kline {
+    pub open_time: i64,
+    pub open: Number,
+    pub high: Number,
+    pub low: Number,
+    pub close: Number,
+    pub volume: Number,
+    pub close_time: i64,
+    pub quote_asset_volume: Number,
+    pub number_of_trades: u64,
+    pub taker_buy_base_asset_volume: Number,
+    pub taker_buy_quote_asset_volume: Number,
+}
+
+impl<'de> Deserialize<'de> for Kline {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (
+            open_time,
+            Quoted(open),
+            Quoted(high),
+            Quoted(low),
+            Quoted(close),
+            Quoted(volume),
+            close_time,
+            Quoted(quote_asset_volume),
+            number_of_trades,
+            Quoted(taker_buy_base_asset_volume),
+            Quoted(taker_buy_quote_asset_volume),
+            IgnoredAny,
+        ) = Deserialize::deserialize(deserializer)?;
+
+        Ok(Kline {
+            open_time,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            close_time,
+            quote_asset_volume,
+            number_of_trades,
+            taker_buy_base_asset_volume,
+            taker_buy_quote_asset_volume,
+        })
+    }
+}
+
+/// Response of `MarketDataClient::get_average_price`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AvgPrice {
+    pub mins: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+}
+
+/// Response of `MarketDataClient::get_24hr_ticker_price`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker24h {
+    pub symbol: String,
+    #[serde(deserialize_with = "de::number")]
+    pub price_change: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub price_change_percent: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub weighted_avg_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub prev_close_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub last_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub last_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub bid_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub bid_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub ask_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub ask_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub open_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub high_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub low_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub volume: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub quote_volume: Number,
+    pub open_time: i64,
+    pub close_time: i64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+/// Response of `MarketDataClient::get_price_ticker`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct PriceTicker {
+    pub symbol: String,
+    #[serde(deserialize_with = "de::number")]
+    pub price: Number,
+}
+
+/// Response of `MarketDataClient::get_order_book_ticker`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    pub symbol: String,
+    #[serde(deserialize_with = "de::number")]
+    pub bid_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub bid_qty: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub ask_price: Number,
+    #[serde(deserialize_with = "de::number")]
+    pub ask_qty: Number,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn decodes_order_books() {
+        let book: OrderBook = serde_json::from_str(r#"{
+            "lastUpdateId": 1027024,
+            "bids": [["4.00000000", "431.00000000"]],
+            "asks": [["4.00000200", "12.00000000"]]
+        }"#).unwrap();
+
+        assert_eq!(book.last_update_id, 1027024);
+        assert_eq!(book.bids, vec![PriceLevel { price: number("4"), qty: number("431") }]);
+        assert_eq!(book.asks, vec![PriceLevel { price: number("4.000002"), qty: number("12") }]);
+    }
+
+    #[test]
+    fn decodes_trades() {
+        let trade: Trade = serde_json::from_str(r#"{
+            "id": 28457, "price": "4.00000100", "qty": "12.00000000", "quoteQty": "48.000012",
+            "time": 1499865549590, "isBuyerMaker": true, "isBestMatch": true
+        }"#).unwrap();
+        assert_eq!((trade.id, trade.price, trade.quote_qty), (28457, number("4.000001"), number("48.000012")));
+
+        let trade: AggTrade = serde_json::from_str(r#"{
+            "a": 26129, "p": "0.01633102", "q": "4.70443515", "f": 27781, "l": 27781,
+            "T": 1498793709153, "m": true, "M": true
+        }"#).unwrap();
+        assert_eq!((trade.id, trade.qty, trade.time), (26129, number("4.70443515"), 1498793709153));
+    }
+
+    #[test]
+    fn decodes_tickers() {
+        let ticker: Ticker24h = serde_json::from_str(r#"{
+            "symbol": "BNBBTC", "priceChange": "-94.99999800", "priceChangePercent": "-95.960",
+            "weightedAvgPrice": "0.29628482", "prevClosePrice": "0.10002000", "lastPrice": "4.00000200",
+            "lastQty": "200.00000000", "bidPrice": "4.00000000", "bidQty": "100.00000000",
+            "askPrice": "4.00000200", "askQty": "100.00000000", "openPrice": "99.00000000",
+            "highPrice": "100.00000000", "lowPrice": "0.10000000", "volume": "8913.30000000",
+            "quoteVolume": "15.30000000", "openTime": 1499783499040, "closeTime": 1499869899040,
+            "firstId": 28385, "lastId": 28460, "count": 76
+        }"#).unwrap();
+        assert_eq!((ticker.price_change, ticker.count), (number("-94.999998"), 76));
+
+        let average: AvgPrice = serde_json::from_str(r#"{"mins": 5, "price": "9.35751834"}"#).unwrap();
+        assert_eq!(average.price, number("9.35751834"));
+
+        let ticker: BookTicker = serde_json::from_str(r#"{
+            "symbol": "LTCBTC", "bidPrice": "4.00000000", "bidQty": "431.00000000",
+            "askPrice": "4.00000200", "askQty": "9.00000000"
+        }"#).unwrap();
+        assert_eq!(ticker.ask_qty, number("9"));
+    }
+}
@@ -0,0 +1,21 @@
+//! Typed response models.
+//!
+//! Binance encodes most prices and quantities as quoted decimals;
+//! these types decode them into numbers.
+
+mod account;
+mod de;
+mod futures;
+mod general;
+mod margin;
+mod market;
+mod stream;
+mod user_data;
+
+pub use self::account::*;
+pub use self::futures::*;
+pub use self::general::*;
+pub use self::margin::*;
+pub use self::market::*;
+pub use self::stream::*;
+pub use self::user_data::*;
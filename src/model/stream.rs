@@ -0,0 +1,538 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::param::{Interval, Number, OrderType, Side, TimeInForce};
+use crate::ws_stream::{Channel, ContractType};
+use super::de;
+use super::account::OrderStatus;
+use super::market::{OrderBook, PriceLevel};
+use super::user_data::UserDataEvent;
+
+/// Payload of `Channel::AggTrade`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AggTradeEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "a")]
+    pub id: u64,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(rename = "q", deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(rename = "f")]
+    pub first_trade_id: u64,
+    #[serde(rename = "l")]
+    pub last_trade_id: u64,
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+/// Payload of `Channel::Trade`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TradeEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "t")]
+    pub id: u64,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(rename = "q", deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+/// Payload of `Channel::Kline`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct KlineEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "k")]
+    pub kline: StreamKline,
+}
+
+/// The candlestick carried by a [`KlineEvent`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct StreamKline {
+    #[serde(rename = "t")]
+    pub open_time: i64,
+    #[serde(rename = "T")]
+    pub close_time: i64,
+    #[serde(rename = "i")]
+    pub interval: Interval,
+    #[serde(rename = "f")]
+    pub first_trade_id: i64,
+    #[serde(rename = "L")]
+    pub last_trade_id: i64,
+    #[serde(rename = "o", deserialize_with = "de::number")]
+    pub open: Number,
+    #[serde(rename = "c", deserialize_with = "de::number")]
+    pub close: Number,
+    #[serde(rename = "h", deserialize_with = "de::number")]
+    pub high: Number,
+    #[serde(rename = "l", deserialize_with = "de::number")]
+    pub low: Number,
+    #[serde(rename = "v", deserialize_with = "de::number")]
+    pub volume: Number,
+    #[serde(rename = "n")]
+    pub number_of_trades: u64,
+    /// Whether this kline is closed.
+    #[serde(rename = "x")]
+    pub is_closed: bool,
+    #[serde(rename = "q", deserialize_with = "de::number")]
+    pub quote_asset_volume: Number,
+    #[serde(rename = "V", deserialize_with = "de::number")]
+    pub taker_buy_base_asset_volume: Number,
+    #[serde(rename = "Q", deserialize_with = "de::number")]
+    pub taker_buy_quote_asset_volume: Number,
+}
+
+/// Payload of `Channel::MiniTicker` and items of `Channel::AllMiniTickers`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MiniTickerEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c", deserialize_with = "de::number")]
+    pub close: Number,
+    #[serde(rename = "o", deserialize_with = "de::number")]
+    pub open: Number,
+    #[serde(rename = "h", deserialize_with = "de::number")]
+    pub high: Number,
+    #[serde(rename = "l", deserialize_with = "de::number")]
+    pub low: Number,
+    #[serde(rename = "v", deserialize_with = "de::number")]
+    pub volume: Number,
+    #[serde(rename = "q", deserialize_with = "de::number")]
+    pub quote_volume: Number,
+}
+
+/// Payload of `Channel::Ticker` and items of `Channel::AllTickers`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TickerEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub price_change: Number,
+    #[serde(rename = "P", deserialize_with = "de::number")]
+    pub price_change_percent: Number,
+    #[serde(rename = "w", deserialize_with = "de::number")]
+    pub weighted_avg_price: Number,
+    #[serde(rename = "x", deserialize_with = "de::number")]
+    pub prev_close_price: Number,
+    #[serde(rename = "c", deserialize_with = "de::number")]
+    pub last_price: Number,
+    #[serde(rename = "Q", deserialize_with = "de::number")]
+    pub last_qty: Number,
+    #[serde(rename = "b", deserialize_with = "de::number")]
+    pub bid_price: Number,
+    #[serde(rename = "B", deserialize_with = "de::number")]
+    pub bid_qty: Number,
+    #[serde(rename = "a", deserialize_with = "de::number")]
+    pub ask_price: Number,
+    #[serde(rename = "A", deserialize_with = "de::number")]
+    pub ask_qty: Number,
+    #[serde(rename = "o", deserialize_with = "de::number")]
+    pub open_price: Number,
+    #[serde(rename = "h", deserialize_with = "de::number")]
+    pub high_price: Number,
+    #[serde(rename = "l", deserialize_with = "de::number")]
+    pub low_price: Number,
+    #[serde(rename = "v", deserialize_with = "de::number")]
+    pub volume: Number,
+    #[serde(rename = "q", deserialize_with = "de::number")]
+    pub quote_volume: Number,
+    #[serde(rename = "O")]
+    pub open_time: i64,
+    #[serde(rename = "C")]
+    pub close_time: i64,
+    #[serde(rename = "F")]
+    pub first_id: i64,
+    #[serde(rename = "L")]
+    pub last_id: i64,
+    #[serde(rename = "n")]
+    pub count: u64,
+}
+
+/// Payload of `Channel::BookTicker` and `Channel::AllBookTickers`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BookTickerEvent {
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b", deserialize_with = "de::number")]
+    pub bid_price: Number,
+    #[serde(rename = "B", deserialize_with = "de::number")]
+    pub bid_qty: Number,
+    #[serde(rename = "a", deserialize_with = "de::number")]
+    pub ask_price: Number,
+    #[serde(rename = "A", deserialize_with = "de::number")]
+    pub ask_qty: Number,
+}
+
+/// Payload of `Channel::Depth`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DepthEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub final_update_id: u64,
+    #[serde(rename = "b")]
+    pub bids: Vec<PriceLevel>,
+    #[serde(rename = "a")]
+    pub asks: Vec<PriceLevel>,
+}
+
+/// Payload of `Channel::MarkPrice` and items of `Channel::AllMarkPrices`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarkPriceEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub mark_price: Number,
+    #[serde(rename = "i", default, deserialize_with = "de::optional_number")]
+    pub index_price: Option<Number>,
+    /// Only meaningful in the last hour before settlement.
+    #[serde(rename = "P", deserialize_with = "de::number")]
+    pub estimated_settle_price: Number,
+    /// `None` for delivery contracts, which don't pay funding.
+    #[serde(rename = "r", default, deserialize_with = "de::optional_number")]
+    pub funding_rate: Option<Number>,
+    #[serde(rename = "T")]
+    pub next_funding_time: i64,
+}
+
+/// Payload of `Channel::LiquidationOrder` and `Channel::AllLiquidationOrders`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct LiquidationOrderEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "o")]
+    pub order: LiquidationOrder,
+}
+
+/// The order carried by a [`LiquidationOrderEvent`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct LiquidationOrder {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "S")]
+    pub side: Side,
+    #[serde(rename = "o")]
+    pub order_type: OrderType,
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "q", deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(rename = "ap", deserialize_with = "de::number")]
+    pub avg_price: Number,
+    #[serde(rename = "X")]
+    pub order_status: OrderStatus,
+    #[serde(rename = "l", deserialize_with = "de::number")]
+    pub last_filled_qty: Number,
+    #[serde(rename = "z", deserialize_with = "de::number")]
+    pub cumulative_filled_qty: Number,
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+}
+
+/// Payload of `Channel::ContinuousKline`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ContinuousKlineEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "ct")]
+    pub contract_type: ContractType,
+    /// Trade ids of the kline are update ids here.
+    #[serde(rename = "k")]
+    pub kline: StreamKline,
+}
+
+/// Payload of `Channel::IndexPrice`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct IndexPriceEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "i")]
+    pub pair: String,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub index_price: Number,
+}
+
+/// Payload of `Channel::CompositeIndex`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CompositeIndexEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(rename = "c")]
+    pub composition: Vec<IndexComponent>,
+}
+
+/// Asset within a [`CompositeIndexEvent`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct IndexComponent {
+    #[serde(rename = "b")]
+    pub base_asset: String,
+    #[serde(rename = "q")]
+    pub quote_asset: String,
+    #[serde(rename = "w", deserialize_with = "de::number")]
+    pub weight_in_qty: Number,
+    #[serde(rename = "W", deserialize_with = "de::number")]
+    pub weight_in_percentage: Number,
+    #[serde(rename = "i", deserialize_with = "de::number")]
+    pub index_price: Number,
+}
+
+/// A decoded message of a websocket channel.
+#[derive(Clone, Debug)]
+pub enum Event {
+    AggTrade(AggTradeEvent),
+    Trade(TradeEvent),
+    Kline(KlineEvent),
+    MiniTicker(MiniTickerEvent),
+    AllMiniTickers(Vec<MiniTickerEvent>),
+    Ticker(TickerEvent),
+    AllTickers(Vec<TickerEvent>),
+    BookTicker(BookTickerEvent),
+    PartialDepth(OrderBook),
+    Depth(DepthEvent),
+    UserData(UserDataEvent),
+    MarkPrice(MarkPriceEvent),
+    AllMarkPrices(Vec<MarkPriceEvent>),
+    LiquidationOrder(LiquidationOrderEvent),
+    ContinuousKline(ContinuousKlineEvent),
+    IndexPrice(IndexPriceEvent),
+    CompositeIndex(CompositeIndexEvent),
+    /// The stream reconnected; messages of the channel may have been missed.
+    Reconnected,
+    /// Payload that could not be decoded for its channel; kept as is.
+    Unknown(Value),
+}
+
+impl Event {
+    pub(crate) fn decode(channel: &Channel<'_>, data: Value) -> Self {
+        let event = match channel {
+            Channel::AggTrade(_) => AggTradeEvent::deserialize(&data).map(Event::AggTrade),
+            Channel::Trade(_) => TradeEvent::deserialize(&data).map(Event::Trade),
+            Channel::Kline(..) => KlineEvent::deserialize(&data).map(Event::Kline),
+            Channel::MiniTicker(_) => MiniTickerEvent::deserialize(&data).map(Event::MiniTicker),
+            Channel::AllMiniTickers => Vec::deserialize(&data).map(Event::AllMiniTickers),
+            Channel::Ticker(_) => TickerEvent::deserialize(&data).map(Event::Ticker),
+            Channel::AllTickers => Vec::deserialize(&data).map(Event::AllTickers),
+            Channel::BookTicker(_) | Channel::AllBookTickers => {
+                BookTickerEvent::deserialize(&data).map(Event::BookTicker)
+            }
+            Channel::PartialDepth(..) => OrderBook::deserialize(&data).map(Event::PartialDepth),
+            Channel::Depth(..) => DepthEvent::deserialize(&data).map(Event::Depth),
+            Channel::UserData(_) => UserDataEvent::deserialize(&data).map(Event::UserData),
+            Channel::MarkPrice(_) => MarkPriceEvent::deserialize(&data).map(Event::MarkPrice),
+            Channel::AllMarkPrices => Vec::deserialize(&data).map(Event::AllMarkPrices),
+            Channel::LiquidationOrder(_) | Channel::AllLiquidationOrders => {
+                LiquidationOrderEvent::deserialize(&data).map(Event::LiquidationOrder)
+            }
+            Channel::ContinuousKline(..) => {
+                ContinuousKlineEvent::deserialize(&data).map(Event::ContinuousKline)
+            }
+            Channel::IndexPrice(_) => IndexPriceEvent::deserialize(&data).map(Event::IndexPrice),
+            Channel::CompositeIndex(_) => {
+                CompositeIndexEvent::deserialize(&data).map(Event::CompositeIndex)
+            }
+        };
+
+        event.unwrap_or(Event::Unknown(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::ws_stream::Speed;
+    use super::*;
+
+    fn number(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    fn payload(json: &str) -> Value {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn decodes_trade_events() {
+        let event = Event::decode(&Channel::Trade("BNBBTC"), json!({
+            "e": "trade", "E": 123456789, "s": "BNBBTC", "t": 12345, "p": "0.001", "q": "100",
+            "b": 88, "a": 50, "T": 123456785, "m": true, "M": true
+        }));
+        match event {
+            Event::Trade(trade) => assert_eq!((trade.id, trade.price, trade.qty), (12345, number("0.001"), number("100"))),
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        let event = Event::decode(&Channel::AggTrade("BNBBTC"), json!({
+            "e": "aggTrade", "E": 123456789, "s": "BNBBTC", "a": 12345, "p": "0.001", "q": "100",
+            "f": 100, "l": 105, "T": 123456785, "m": true, "M": true
+        }));
+        match event {
+            Event::AggTrade(trade) => assert_eq!((trade.first_trade_id, trade.last_trade_id), (100, 105)),
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn decodes_kline_events() {
+        let event = Event::decode(&Channel::Kline("BNBBTC", Interval::OneMinute), json!({
+            "e": "kline", "E": 123456789, "s": "BNBBTC",
+            "k": {
+                "t": 123400000, "T": 123460000, "s": "BNBBTC", "i": "1m", "f": 100, "L": 200,
+                "o": "0.0010", "c": "0.0020", "h": "0.0025", "l": "0.0015", "v": "1000", "n": 100,
+                "x": false, "q": "1.0000", "V": "500", "Q": "0.500", "B": "123456"
+            }
+        }));
+        match event {
+            Event::Kline(event) => {
+                assert!(matches!(event.kline.interval, Interval::OneMinute));
+                assert_eq!((event.kline.close, event.kline.number_of_trades), (number("0.002"), 100));
+                assert!(!event.kline.is_closed);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn decodes_ticker_and_depth_events() {
+        let event = Event::decode(&Channel::AllMiniTickers, json!([{
+            "e": "24hrMiniTicker", "E": 123456789, "s": "BNBBTC", "c": "0.0025", "o": "0.0010",
+            "h": "0.0025", "l": "0.0010", "v": "10000", "q": "18"
+        }]));
+        match event {
+            Event::AllMiniTickers(tickers) => assert_eq!(tickers[0].quote_volume, number("18")),
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        let event = Event::decode(&Channel::BookTicker("BNBUSDT"), json!({
+            "u": 400900217, "s": "BNBUSDT", "b": "25.35190000", "B": "31.21000000",
+            "a": "25.36520000", "A": "40.66000000"
+        }));
+        match event {
+            Event::BookTicker(ticker) => assert_eq!((ticker.update_id, ticker.ask_qty), (400900217, number("40.66"))),
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        let event = Event::decode(&Channel::Depth("BNBBTC", Speed::HundredMillis), json!({
+            "e": "depthUpdate", "E": 123456789, "s": "BNBBTC", "U": 157, "u": 160,
+            "b": [["0.0024", "10"]], "a": [["0.0026", "100"]]
+        }));
+        match event {
+            Event::Depth(depth) => {
+                assert_eq!((depth.first_update_id, depth.final_update_id), (157, 160));
+                assert_eq!(depth.asks, vec![PriceLevel { price: number("0.0026"), qty: number("100") }]);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn decodes_futures_market_events() {
+        let event = Event::decode(&Channel::MarkPrice("BTCUSDT"), payload(r#"{
+            "e": "markPriceUpdate", "E": 1562305380000, "s": "BTCUSDT", "p": "11794.15000000",
+            "i": "11784.62659091", "P": "11784.25641265", "r": "0.00038167", "T": 1562306400000
+        }"#));
+        match event {
+            Event::MarkPrice(price) => {
+                assert_eq!(price.mark_price, number("11794.15"));
+                assert_eq!(price.funding_rate, Some(number("0.00038167")));
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        // Delivery contracts send an empty funding rate.
+        let event = Event::decode(&Channel::MarkPrice("BTCUSD_200626"), payload(r#"{
+            "e": "markPriceUpdate", "E": 1596095725000, "s": "BTCUSD_200626", "p": "11164.71",
+            "P": "11186.75", "r": "", "T": 0
+        }"#));
+        match event {
+            Event::MarkPrice(price) => assert_eq!((price.index_price, price.funding_rate), (None, None)),
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        let event = Event::decode(&Channel::AllLiquidationOrders, payload(r#"{
+            "e": "forceOrder", "E": 1568014460893,
+            "o": {
+                "s": "BTCUSDT", "S": "SELL", "o": "LIMIT", "f": "IOC", "q": "0.014", "p": "9910",
+                "ap": "9910", "X": "FILLED", "l": "0.014", "z": "0.014", "T": 1568014460893
+            }
+        }"#));
+        match event {
+            Event::LiquidationOrder(event) => {
+                assert_eq!((event.order.side, event.order.order_status), (Side::Sell, OrderStatus::Filled));
+                assert_eq!(event.order.avg_price, number("9910"));
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn decodes_futures_kline_and_index_events() {
+        let channel = Channel::ContinuousKline("BTCUSDT", ContractType::Perpetual, Interval::OneMinute);
+        let event = Event::decode(&channel, payload(r#"{
+            "e": "continuous_kline", "E": 1607443058651, "ps": "BTCUSDT", "ct": "PERPETUAL",
+            "k": {
+                "t": 1607443020000, "T": 1607443079999, "i": "1m", "f": 116467658886,
+                "L": 116468012423, "o": "18787.00", "c": "18804.04", "h": "18804.04",
+                "l": "18786.54", "v": "197.664", "n": 543, "x": false, "q": "3715253.19494",
+                "V": "184.769", "Q": "3472925.84746", "B": "0"
+            }
+        }"#));
+        match event {
+            Event::ContinuousKline(event) => {
+                assert_eq!((event.pair.as_str(), event.contract_type), ("BTCUSDT", ContractType::Perpetual));
+                assert_eq!(event.kline.close, number("18804.04"));
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        let event = Event::decode(&Channel::IndexPrice("BTCUSD"), payload(r#"{
+            "e": "indexPriceUpdate", "E": 1591261236000, "i": "BTCUSD", "p": "9636.57860000"
+        }"#));
+        match event {
+            Event::IndexPrice(price) => assert_eq!(price.index_price, number("9636.5786")),
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn keeps_payloads_it_cannot_decode() {
+        let data = json!({ "e": "trade", "E": 123456789 });
+        match Event::decode(&Channel::Trade("BNBBTC"), data.clone()) {
+            Event::Unknown(value) => assert_eq!(value, data),
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+}
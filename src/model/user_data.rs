@@ -0,0 +1,509 @@
+use serde::Deserialize;
+use crate::param::{Number, OrderType, PositionSide, Side, TimeInForce, WorkingType};
+use super::account::OrderStatus;
+use super::de;
+
+/// Response of `UserDataClient::start_stream`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListenKey {
+    pub listen_key: String,
+}
+
+/// Reason an `executionReport` was sent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionType {
+    New,
+    Canceled,
+    Replaced,
+    Rejected,
+    Trade,
+    Expired,
+    TradePrevention,
+    /// Futures only: liquidation.
+    Calculated,
+    /// Futures only: the order was modified.
+    Amendment,
+}
+
+/// Status of an order list as a whole.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListStatusType {
+    Response,
+    ExecStarted,
+    AllDone,
+}
+
+/// Status of the orders within an order list.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListOrderStatus {
+    Executing,
+    AllDone,
+    Reject,
+}
+
+/// Order update sent on the user data stream.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ExecutionReport {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: Side,
+    #[serde(rename = "o")]
+    pub order_type: OrderType,
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "q", deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(rename = "P", deserialize_with = "de::number")]
+    pub stop_price: Number,
+    #[serde(rename = "F", deserialize_with = "de::number")]
+    pub iceberg_qty: Number,
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    /// Client order id of the order being canceled; empty otherwise.
+    #[serde(rename = "C")]
+    pub orig_client_order_id: String,
+    #[serde(rename = "x")]
+    pub execution_type: ExecutionType,
+    #[serde(rename = "X")]
+    pub order_status: OrderStatus,
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+    #[serde(rename = "i")]
+    pub order_id: i64,
+    #[serde(rename = "l", deserialize_with = "de::number")]
+    pub last_executed_qty: Number,
+    #[serde(rename = "z", deserialize_with = "de::number")]
+    pub cumulative_filled_qty: Number,
+    #[serde(rename = "L", deserialize_with = "de::number")]
+    pub last_executed_price: Number,
+    #[serde(rename = "n", deserialize_with = "de::number")]
+    pub commission: Number,
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "w")]
+    pub is_on_book: bool,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "O")]
+    pub creation_time: i64,
+    #[serde(rename = "Z", deserialize_with = "de::number")]
+    pub cumulative_quote_qty: Number,
+    #[serde(rename = "Y", deserialize_with = "de::number")]
+    pub last_quote_qty: Number,
+    #[serde(rename = "Q", deserialize_with = "de::number")]
+    pub quote_order_qty: Number,
+}
+
+/// Balance of a single asset within an [`OutboundAccountPosition`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct AssetBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "f", deserialize_with = "de::number")]
+    pub free: Number,
+    #[serde(rename = "l", deserialize_with = "de::number")]
+    pub locked: Number,
+}
+
+/// Balances of the assets that changed with an account update.
+#[derive(Clone, Debug, Deserialize)]
+pub struct OutboundAccountPosition {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "u")]
+    pub last_update_time: i64,
+    #[serde(rename = "B")]
+    pub balances: Vec<AssetBalance>,
+}
+
+/// Deposit, withdrawal or transfer of funds.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BalanceUpdate {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "d", deserialize_with = "de::number")]
+    pub delta: Number,
+    #[serde(rename = "T")]
+    pub clear_time: i64,
+}
+
+/// Order within a [`ListStatus`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListStatusOrder {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "i")]
+    pub order_id: i64,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+}
+
+/// Update of an order list, e.g. an oco order.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListStatus {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    #[serde(rename = "c")]
+    pub contingency_type: String,
+    #[serde(rename = "l")]
+    pub list_status_type: ListStatusType,
+    #[serde(rename = "L")]
+    pub list_order_status: ListOrderStatus,
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+    #[serde(rename = "C")]
+    pub list_client_order_id: String,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "O")]
+    pub orders: Vec<ListStatusOrder>,
+}
+
+/// Sent when the listen key of the stream expired.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListenKeyExpired {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+}
+
+/// Balance of a single asset within an [`AccountUpdate`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountUpdateBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "wb", deserialize_with = "de::number")]
+    pub wallet_balance: Number,
+    #[serde(rename = "cw", deserialize_with = "de::number")]
+    pub cross_wallet_balance: Number,
+    /// Change except for PnL and commission; not sent by COIN-M futures.
+    #[serde(rename = "bc", default, deserialize_with = "de::optional_number")]
+    pub balance_change: Option<Number>,
+}
+
+/// Position of a single symbol within an [`AccountUpdate`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountUpdatePosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "pa", deserialize_with = "de::number")]
+    pub position_amt: Number,
+    #[serde(rename = "ep", deserialize_with = "de::number")]
+    pub entry_price: Number,
+    #[serde(rename = "cr", deserialize_with = "de::number")]
+    pub accumulated_realized: Number,
+    #[serde(rename = "up", deserialize_with = "de::number")]
+    pub unrealized_pnl: Number,
+    /// `isolated` or `cross`.
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+    #[serde(rename = "iw", deserialize_with = "de::number")]
+    pub isolated_wallet: Number,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+}
+
+/// Balances and positions that changed, within an [`AccountUpdate`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountUpdateData {
+    /// Reason of the update, e.g. `ORDER` or `FUNDING_FEE`.
+    #[serde(rename = "m")]
+    pub reason: String,
+    #[serde(rename = "B")]
+    pub balances: Vec<AccountUpdateBalance>,
+    #[serde(rename = "P")]
+    pub positions: Vec<AccountUpdatePosition>,
+}
+
+/// Futures balance and position update.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountUpdate {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "a")]
+    pub data: AccountUpdateData,
+}
+
+/// The order carried by an [`OrderTradeUpdate`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct FuturesOrderUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: Side,
+    #[serde(rename = "o")]
+    pub order_type: OrderType,
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "q", deserialize_with = "de::number")]
+    pub qty: Number,
+    #[serde(rename = "p", deserialize_with = "de::number")]
+    pub price: Number,
+    #[serde(rename = "ap", deserialize_with = "de::number")]
+    pub avg_price: Number,
+    #[serde(rename = "sp", deserialize_with = "de::number")]
+    pub stop_price: Number,
+    #[serde(rename = "x")]
+    pub execution_type: ExecutionType,
+    #[serde(rename = "X")]
+    pub order_status: OrderStatus,
+    #[serde(rename = "i")]
+    pub order_id: i64,
+    #[serde(rename = "l", deserialize_with = "de::number")]
+    pub last_filled_qty: Number,
+    #[serde(rename = "z", deserialize_with = "de::number")]
+    pub cumulative_filled_qty: Number,
+    #[serde(rename = "L", deserialize_with = "de::number")]
+    pub last_filled_price: Number,
+    /// Only sent with a commission.
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "n", default, deserialize_with = "de::optional_number")]
+    pub commission: Option<Number>,
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "R")]
+    pub reduce_only: bool,
+    #[serde(rename = "wt")]
+    pub working_type: WorkingType,
+    #[serde(rename = "ot")]
+    pub orig_type: OrderType,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+    #[serde(rename = "cp", default)]
+    pub close_position: bool,
+    #[serde(rename = "rp", deserialize_with = "de::number")]
+    pub realized_profit: Number,
+}
+
+/// Futures order update.
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrderTradeUpdate {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "o")]
+    pub order: FuturesOrderUpdate,
+}
+
+/// Position at risk within a [`MarginCall`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarginCallPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+    #[serde(rename = "pa", deserialize_with = "de::number")]
+    pub position_amt: Number,
+    /// `ISOLATED` or `CROSSED`.
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+    #[serde(rename = "iw", deserialize_with = "de::number")]
+    pub isolated_wallet: Number,
+    #[serde(rename = "mp", deserialize_with = "de::number")]
+    pub mark_price: Number,
+    #[serde(rename = "up", deserialize_with = "de::number")]
+    pub unrealized_pnl: Number,
+    #[serde(rename = "mm", deserialize_with = "de::number")]
+    pub maintenance_margin: Number,
+}
+
+/// Futures positions nearing liquidation.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarginCall {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// Only sent for crossed positions.
+    #[serde(rename = "cw", default, deserialize_with = "de::optional_number")]
+    pub cross_wallet_balance: Option<Number>,
+    #[serde(rename = "p")]
+    pub positions: Vec<MarginCallPosition>,
+}
+
+/// Payload of `Channel::UserData`.
+/// # Example
+///
+/// ```
+/// use tokio_binance::{model::UserDataEvent, Number};
+///
+/// let event: UserDataEvent = serde_json::from_str(r#"{
+///     "e": "balanceUpdate", "E": 1573200697110,
+///     "a": "BTC", "d": "100.00000000", "T": 1573200697068
+/// }"#).unwrap();
+///
+/// if let UserDataEvent::BalanceUpdate(update) = event {
+///     assert_eq!(update.delta, "100".parse::<Number>().unwrap());
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "e")]
+pub enum UserDataEvent {
+    #[serde(rename = "executionReport")]
+    ExecutionReport(ExecutionReport),
+    #[serde(rename = "outboundAccountPosition")]
+    OutboundAccountPosition(OutboundAccountPosition),
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(BalanceUpdate),
+    #[serde(rename = "listStatus")]
+    ListStatus(ListStatus),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpired),
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(AccountUpdate),
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(OrderTradeUpdate),
+    #[serde(rename = "MARGIN_CALL")]
+    MarginCall(MarginCall),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn decodes_execution_reports() {
+        let event: UserDataEvent = serde_json::from_str(r#"{
+            "e": "executionReport", "E": 1499405658658, "s": "ETHBTC", "c": "mUvoqJxFIILMdfAW5iGSOW",
+            "S": "BUY", "o": "LIMIT", "f": "GTC", "q": "1.00000000", "p": "0.10264410",
+            "P": "0.00000000", "F": "0.00000000", "g": -1, "C": "", "x": "NEW", "X": "NEW",
+            "r": "NONE", "i": 4293153, "l": "0.00000000", "z": "0.00000000", "L": "0.00000000",
+            "n": "0", "N": null, "T": 1499405658657, "t": -1, "I": 8641984, "w": true, "m": false,
+            "M": false, "O": 1499405658657, "Z": "0.00000000", "Y": "0.00000000", "Q": "0.00000000"
+        }"#).unwrap();
+
+        match event {
+            UserDataEvent::ExecutionReport(report) => {
+                assert_eq!((report.execution_type, report.order_status), (ExecutionType::New, OrderStatus::New));
+                assert_eq!((report.order_id, report.price), (4293153, number("0.1026441")));
+                assert_eq!(report.commission_asset, None);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn decodes_account_events() {
+        let event: UserDataEvent = serde_json::from_str(r#"{
+            "e": "outboundAccountPosition", "E": 1564034571105, "u": 1564034571073,
+            "B": [{ "a": "ETH", "f": "10000.000000", "l": "0.000000" }]
+        }"#).unwrap();
+        match event {
+            UserDataEvent::OutboundAccountPosition(position) => assert_eq!(position.balances[0].free, number("10000")),
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        let event: UserDataEvent = serde_json::from_str(r#"{
+            "e": "listStatus", "E": 1564035303637, "s": "ETHBTC", "g": 2, "c": "OCO",
+            "l": "EXEC_STARTED", "L": "EXECUTING", "r": "NONE", "C": "F4QN4G8DlFATFlIUQ0cjdD",
+            "T": 1564035303625,
+            "O": [
+                { "s": "ETHBTC", "i": 17, "c": "AJYsMjErWJesZvqlJCTUgL" },
+                { "s": "ETHBTC", "i": 18, "c": "bfYPSQdLoqAJeNrOr9adzq" }
+            ]
+        }"#).unwrap();
+        match event {
+            UserDataEvent::ListStatus(list) => {
+                assert_eq!((list.list_status_type, list.list_order_status), (ListStatusType::ExecStarted, ListOrderStatus::Executing));
+                assert_eq!(list.orders[1].order_id, 18);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        let event: UserDataEvent = serde_json::from_str(r#"{"e": "listenKeyExpired", "E": 1576653824250}"#).unwrap();
+        assert!(matches!(event, UserDataEvent::ListenKeyExpired(_)));
+    }
+
+    #[test]
+    fn decodes_futures_account_updates() {
+        let event: UserDataEvent = serde_json::from_str(r#"{
+            "e": "ACCOUNT_UPDATE", "E": 1564745798939, "T": 1564745798938,
+            "a": {
+                "m": "ORDER",
+                "B": [{ "a": "USDT", "wb": "122624.12345678", "cw": "100.12345678", "bc": "50.12345678" }],
+                "P": [{
+                    "s": "BTCUSDT", "pa": "0", "ep": "0.00000", "bep": "0", "cr": "200", "up": "0",
+                    "mt": "isolated", "iw": "0.00000000", "ps": "BOTH"
+                }]
+            }
+        }"#).unwrap();
+        match event {
+            UserDataEvent::AccountUpdate(update) => {
+                assert_eq!(update.data.reason, "ORDER");
+                assert_eq!(update.data.balances[0].balance_change, Some(number("50.12345678")));
+                assert_eq!(update.data.positions[0].position_side, PositionSide::Both);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        let event: UserDataEvent = serde_json::from_str(r#"{
+            "e": "MARGIN_CALL", "E": 1587727187525, "cw": "3.16812045",
+            "p": [{
+                "s": "ETHUSDT", "ps": "LONG", "pa": "1.327", "mt": "CROSSED", "iw": "0",
+                "mp": "187.17127", "up": "-1.166074", "mm": "1.614445"
+            }]
+        }"#).unwrap();
+        match event {
+            UserDataEvent::MarginCall(call) => {
+                assert_eq!(call.cross_wallet_balance, Some(number("3.16812045")));
+                assert_eq!(call.positions[0].maintenance_margin, number("1.614445"));
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn decodes_futures_order_updates() {
+        let event: UserDataEvent = serde_json::from_str(r#"{
+            "e": "ORDER_TRADE_UPDATE", "E": 1568879465651, "T": 1568879465650,
+            "o": {
+                "s": "BTCUSDT", "c": "TEST", "S": "SELL", "o": "TRAILING_STOP_MARKET", "f": "GTC",
+                "q": "0.001", "p": "0", "ap": "0", "sp": "7103.04", "x": "NEW", "X": "NEW",
+                "i": 8886774, "l": "0", "z": "0", "L": "0", "N": "USDT", "n": "0",
+                "T": 1568879465650, "t": 0, "b": "0", "a": "9.91", "m": false, "R": false,
+                "wt": "CONTRACT_PRICE", "ot": "TRAILING_STOP_MARKET", "ps": "LONG", "cp": false,
+                "AP": "7476.89", "cr": "5.0", "rp": "0"
+            }
+        }"#).unwrap();
+        match event {
+            UserDataEvent::OrderTradeUpdate(update) => {
+                assert_eq!(update.order.order_type, OrderType::TrailingStopMarket);
+                assert_eq!((update.order.working_type, update.order.position_side), (WorkingType::ContractPrice, PositionSide::Long));
+                assert_eq!(update.order.stop_price, number("7103.04"));
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+}
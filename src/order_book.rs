@@ -0,0 +1,356 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Duration};
+use log::warn;
+
+use crate::client::MarketDataClient;
+use crate::environment::WsUrl;
+use crate::error::{Error, Kind};
+use crate::model::{DepthEvent, Event, OrderBook, PriceLevel};
+use crate::param::Number;
+use crate::ws_stream::{Channel, Speed, WebSocketStream};
+
+/// Depth of the REST snapshot used to seed the local order book.
+const SNAPSHOT_LIMIT: usize = 1000;
+/// Time to wait before resynchronizing after an error.
+const RESYNC_DELAY: Duration = Duration::from_secs(1);
+/// Time to wait before fetching another snapshot that was older than the buffered events.
+const SNAPSHOT_DELAY: Duration = Duration::from_millis(500);
+/// Snapshots fetched at most before giving up on a resync; each one costs 10 weight.
+const SNAPSHOT_ATTEMPTS: u32 = 5;
+
+/// Local order book kept in sync with the `Channel::Depth` diff stream.
+///
+/// The book is seeded from a `MarketDataClient::get_order_book` snapshot and
+/// updated from the buffered diff events following the update id rules of the
+/// Binance docs. It resynchronizes by itself whenever a gap is detected or the
+/// connection fails. The background task is stopped when the manager is dropped.
+pub struct OrderBookManager {
+    book: Arc<RwLock<LocalBook>>,
+    changes: watch::Receiver<u64>,
+    task: JoinHandle<()>,
+}
+
+impl OrderBookManager {
+    /// Start maintaining the order book of a symbol; `url` may be an `Environment`,
+    /// failing over between its websocket hosts.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{MarketDataClient, OrderBookManager, Speed, BINANCE_US_URL, BINANCE_US_WSS_URL};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = MarketDataClient::connect("<api-key>", BINANCE_US_URL)?;
+    ///     let manager = OrderBookManager::start(client, "BNBUSDT", Speed::HundredMillis, BINANCE_US_WSS_URL);
+    ///     let mut changes = manager.changes();
+    ///
+    ///     while changes.changed().await.is_ok() {
+    ///         println!("bid: {:?} ask: {:?}", manager.best_bid(), manager.best_ask());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn start<S, U>(client: MarketDataClient, symbol: S, speed: Speed, url: U) -> Self
+    where
+        S: Into<String>,
+        U: Into<WsUrl>,
+    {
+        let book = Arc::new(RwLock::new(LocalBook::default()));
+        let (sender, changes) = watch::channel(0);
+
+        let task = tokio::spawn(maintain(
+            client,
+            symbol.into(),
+            speed,
+            url.into(),
+            book.clone(),
+            sender,
+        ));
+
+        Self { book, changes, task }
+    }
+    /// Highest bid, if the book is in sync.
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        let book = self.book.read().unwrap();
+        book.bids.iter().next_back().map(|(price, qty)| PriceLevel { price: price.0, qty: *qty })
+    }
+    /// Lowest ask, if the book is in sync.
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        let book = self.book.read().unwrap();
+        book.asks.iter().next().map(|(price, qty)| PriceLevel { price: price.0, qty: *qty })
+    }
+    /// The best `levels` bids and asks; both are empty while the book is out of sync.
+    pub fn depth(&self, levels: usize) -> OrderBook {
+        let book = self.book.read().unwrap();
+
+        OrderBook {
+            last_update_id: book.last_update_id,
+            bids: book
+                .bids
+                .iter()
+                .rev()
+                .take(levels)
+                .map(|(price, qty)| PriceLevel { price: price.0, qty: *qty })
+                .collect(),
+            asks: book
+                .asks
+                .iter()
+                .take(levels)
+                .map(|(price, qty)| PriceLevel { price: price.0, qty: *qty })
+                .collect(),
+        }
+    }
+    /// Whether the book currently reflects the exchange.
+    pub fn is_synced(&self) -> bool {
+        self.book.read().unwrap().synced
+    }
+    /// Receiver notified with the last applied update id whenever the book changes.
+    pub fn changes(&self) -> watch::Receiver<u64> {
+        self.changes.clone()
+    }
+}
+
+impl Drop for OrderBookManager {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Price usable as an ordered map key.
+#[derive(Copy, Clone, PartialEq)]
+struct Price(Number);
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Prices are parsed from the exchange and are never NaN.
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+#[derive(Default)]
+struct LocalBook {
+    last_update_id: u64,
+    bids: BTreeMap<Price, Number>,
+    asks: BTreeMap<Price, Number>,
+    synced: bool,
+}
+
+impl LocalBook {
+    fn reset(&mut self, snapshot: Option<OrderBook>) {
+        *self = Self::default();
+
+        if let Some(snapshot) = snapshot {
+            self.last_update_id = snapshot.last_update_id;
+            self.bids = snapshot.bids.iter().map(|level| (Price(level.price), level.qty)).collect();
+            self.asks = snapshot.asks.iter().map(|level| (Price(level.price), level.qty)).collect();
+            self.synced = true;
+        }
+    }
+
+    fn apply(&mut self, event: &DepthEvent) {
+        update(&mut self.bids, &event.bids);
+        update(&mut self.asks, &event.asks);
+        self.last_update_id = event.final_update_id;
+    }
+}
+
+/// What to do with a diff event, given the snapshot and the last applied event.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Sequence {
+    /// The event is already part of the snapshot.
+    Skip,
+    Apply,
+    /// Updates were missed; the book has to be resynchronized.
+    Gap,
+}
+
+fn sequence(event: &DepthEvent, snapshot_update_id: u64, previous: Option<u64>) -> Sequence {
+    if event.final_update_id <= snapshot_update_id {
+        return Sequence::Skip;
+    }
+
+    let in_sequence = match previous {
+        Some(previous) => event.first_update_id == previous + 1,
+        // The first event applied has to overlap the snapshot.
+        None => event.first_update_id <= snapshot_update_id + 1,
+    };
+
+    if in_sequence { Sequence::Apply } else { Sequence::Gap }
+}
+
+fn update(side: &mut BTreeMap<Price, Number>, levels: &[PriceLevel]) {
+    for level in levels {
+        if level.qty == Number::default() {
+            side.remove(&Price(level.price));
+        } else {
+            side.insert(Price(level.price), level.qty);
+        }
+    }
+}
+
+async fn maintain(
+    client: MarketDataClient,
+    symbol: String,
+    speed: Speed,
+    url: WsUrl,
+    book: Arc<RwLock<LocalBook>>,
+    sender: watch::Sender<u64>,
+) {
+    loop {
+        if let Err(e) = sync(&client, &symbol, speed, &url, &book, &sender).await {
+            warn!("Order book for {} failed: {}", symbol, e);
+        }
+
+        book.write().unwrap().reset(None);
+        sleep(RESYNC_DELAY).await;
+    }
+}
+
+/// Runs until the stream ends, fails or a gap is detected.
+async fn sync(
+    client: &MarketDataClient,
+    symbol: &str,
+    speed: Speed,
+    url: &WsUrl,
+    book: &RwLock<LocalBook>,
+    sender: &watch::Sender<u64>,
+) -> crate::error::Result<()> {
+    let mut stream = WebSocketStream::connect(Channel::Depth(symbol, speed), url.clone()).await?;
+
+    // Buffer events until the first one is received, then fetch a snapshot
+    // that is at least as recent as that event.
+    let first = match next_depth(&mut stream).await? {
+        Some(event) => event,
+        None => return Ok(()),
+    };
+    let first_update_id = first.first_update_id;
+    let mut buffered = Some(first);
+
+    let mut attempts = 0;
+
+    let snapshot = loop {
+        let snapshot = client.get_order_book(symbol).with_limit(SNAPSHOT_LIMIT).order_book().await?;
+        if snapshot.last_update_id >= first_update_id {
+            break snapshot;
+        }
+
+        attempts += 1;
+        if attempts == SNAPSHOT_ATTEMPTS {
+            let message = format!("{} snapshots of {} were older than the depth stream", attempts, symbol);
+            return Err(Error::new(Kind::OrderBook, Some(message)));
+        }
+        sleep(SNAPSHOT_DELAY).await;
+    };
+    let last_update_id = snapshot.last_update_id;
+    book.write().unwrap().reset(Some(snapshot));
+
+    let mut previous: Option<u64> = None;
+
+    loop {
+        let event = match buffered.take() {
+            Some(event) => event,
+            None => match next_depth(&mut stream).await? {
+                Some(event) => event,
+                None => return Ok(()),
+            },
+        };
+
+        match sequence(&event, last_update_id, previous) {
+            Sequence::Skip => continue,
+            Sequence::Apply => {}
+            Sequence::Gap => {
+                warn!("Gap in depth updates for {}; resynchronizing", symbol);
+                return Ok(());
+            }
+        }
+
+        book.write().unwrap().apply(&event);
+        previous = Some(event.final_update_id);
+        let _ = sender.send(event.final_update_id);
+    }
+}
+
+async fn next_depth(stream: &mut WebSocketStream) -> crate::error::Result<Option<DepthEvent>> {
+    while let Some((_, event)) = stream.event().await? {
+        if let Event::Depth(event) = event {
+            return Ok(Some(event));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(first_update_id: u64, final_update_id: u64, bids: &str, asks: &str) -> DepthEvent {
+        let json = format!(
+            r#"{{"E":0,"s":"BNBUSDT","U":{},"u":{},"b":{},"a":{}}}"#,
+            first_update_id, final_update_id, bids, asks
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn snapshot(last_update_id: u64, bids: &str, asks: &str) -> OrderBook {
+        let json = format!(r#"{{"lastUpdateId":{},"bids":{},"asks":{}}}"#, last_update_id, bids, asks);
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn skips_events_in_the_snapshot() {
+        assert_eq!(sequence(&event(90, 100, "[]", "[]"), 100, None), Sequence::Skip);
+        assert_eq!(sequence(&event(90, 99, "[]", "[]"), 100, Some(99)), Sequence::Skip);
+    }
+
+    #[test]
+    fn first_event_has_to_overlap_the_snapshot() {
+        assert_eq!(sequence(&event(95, 105, "[]", "[]"), 100, None), Sequence::Apply);
+        assert_eq!(sequence(&event(101, 105, "[]", "[]"), 100, None), Sequence::Apply);
+        assert_eq!(sequence(&event(102, 105, "[]", "[]"), 100, None), Sequence::Gap);
+    }
+
+    #[test]
+    fn later_events_have_to_follow_the_previous_one() {
+        assert_eq!(sequence(&event(106, 110, "[]", "[]"), 100, Some(105)), Sequence::Apply);
+        assert_eq!(sequence(&event(107, 110, "[]", "[]"), 100, Some(105)), Sequence::Gap);
+        assert_eq!(sequence(&event(105, 110, "[]", "[]"), 100, Some(105)), Sequence::Gap);
+    }
+
+    #[test]
+    fn applies_updates_and_removes_empty_levels() {
+        let mut book = LocalBook::default();
+        book.reset(Some(snapshot(100, r#"[["10.0","1.0"],["9.0","2.0"]]"#, r#"[["11.0","1.0"]]"#)));
+
+        book.apply(&event(101, 102, r#"[["10.0","0.0"],["9.5","3.0"]]"#, r#"[["10.5","4.0"]]"#));
+
+        let bids: Vec<_> = book.bids.iter().map(|(price, qty)| (price.0, *qty)).collect();
+        let asks: Vec<_> = book.asks.iter().map(|(price, qty)| (price.0, *qty)).collect();
+
+        assert_eq!(bids, vec![("9.0".parse().unwrap(), "2.0".parse().unwrap()), ("9.5".parse().unwrap(), "3.0".parse().unwrap())]);
+        assert_eq!(asks, vec![("10.5".parse().unwrap(), "4.0".parse().unwrap()), ("11.0".parse().unwrap(), "1.0".parse().unwrap())]);
+        assert_eq!(book.last_update_id, 102);
+        assert!(book.synced);
+    }
+
+    #[test]
+    fn reset_without_snapshot_is_out_of_sync() {
+        let mut book = LocalBook::default();
+        book.reset(Some(snapshot(100, r#"[["10.0","1.0"]]"#, "[]")));
+        book.reset(None);
+
+        assert!(!book.synced);
+        assert!(book.bids.is_empty());
+    }
+}
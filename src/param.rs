@@ -1,10 +1,25 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use chrono::Utc;
-use hmac::{Hmac, Mac, NewMac};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value;
-use sha2::Sha256;
+use url::form_urlencoded;
+use crate::signer::Signer;
 
-type HmacSha256 = Hmac<Sha256>;
+/// Numeric type of prices and quantities.
+///
+/// This is `f64` by default. With the `decimal` feature enabled it is
+/// [`rust_decimal::Decimal`], which is sent to Binance exactly as written
+/// and decoded from responses without loss of precision.
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+/// Numeric type of prices and quantities.
+///
+/// This is `f64` by default. With the `decimal` feature enabled it is
+/// [`rust_decimal::Decimal`], which is sent to Binance exactly as written
+/// and decoded from responses without loss of precision.
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
 
 #[derive(Copy, Clone, Debug)]
 pub enum ID<'a> {
@@ -12,16 +27,16 @@ pub enum ID<'a> {
     ClientOId(&'a str),
 }
 
-#[derive(Copy, Clone, Serialize, Deserialize, Debug)]
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Side {
     Buy,
     Sell,
 }
 
-#[derive(Copy, Clone, Serialize)]
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub(super) enum OrderType {
+pub enum OrderType {
     Limit,
     Market,
     StopLoss,
@@ -29,14 +44,129 @@ pub(super) enum OrderType {
     TakeProfit,
     TakeProfitLimit,
     LimitMaker,
+    /// Futures stop-limit order.
+    Stop,
+    /// Futures stop-market order.
+    StopMarket,
+    /// Futures take-profit-market order.
+    TakeProfitMarket,
+    /// Futures trailing stop order.
+    TrailingStopMarket,
 }
 
-#[derive(Copy, Clone, Serialize, Deserialize, Debug)]
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum TimeInForce {
     Gtc,
     Ioc,
     Fok,
+    /// Futures post-only order; canceled instead of taking liquidity.
+    Gtx,
+}
+
+/// Side of a futures position; `Both` in one-way mode.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PositionSide {
+    Both,
+    Long,
+    Short,
+}
+
+/// Price that triggers a futures stop order.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WorkingType {
+    MarkPrice,
+    ContractPrice,
+}
+
+/// Margin mode of a futures symbol.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum MarginType {
+    Isolated,
+    Crossed,
+}
+
+/// Kind of a futures income history entry.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    Transfer,
+    WelcomeBonus,
+    RealizedPnl,
+    FundingFee,
+    Commission,
+    InsuranceClear,
+    ReferralKickback,
+    CommissionRebate,
+    ApiRebate,
+    ContestReward,
+    CrossCollateralTransfer,
+    InternalTransfer,
+}
+
+/// Borrowing or repaying that comes with a margin order.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SideEffectType {
+    NoSideEffect,
+    /// Borrow what the order needs.
+    MarginBuy,
+    /// Repay debt with what the order returns.
+    AutoRepay,
+    /// Borrow what the order needs and repay it once the order is canceled or filled.
+    AutoBorrowRepay,
+}
+
+/// Whether `MarginClient::borrow` or `MarginClient::repay` sent a loan request.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LoanType {
+    Borrow,
+    Repay,
+}
+
+/// Wallets that `WithdrawalClient::universal_transfer` moves funds between.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum TransferType {
+    #[serde(rename = "MAIN_MARGIN")]
+    SpotToMargin,
+    #[serde(rename = "MARGIN_MAIN")]
+    MarginToSpot,
+    #[serde(rename = "MAIN_UMFUTURE")]
+    SpotToUsdMFutures,
+    #[serde(rename = "UMFUTURE_MAIN")]
+    UsdMFuturesToSpot,
+    #[serde(rename = "MAIN_CMFUTURE")]
+    SpotToCoinMFutures,
+    #[serde(rename = "CMFUTURE_MAIN")]
+    CoinMFuturesToSpot,
+    #[serde(rename = "MAIN_FUNDING")]
+    SpotToFunding,
+    #[serde(rename = "FUNDING_MAIN")]
+    FundingToSpot,
+    #[serde(rename = "MARGIN_UMFUTURE")]
+    MarginToUsdMFutures,
+    #[serde(rename = "UMFUTURE_MARGIN")]
+    UsdMFuturesToMargin,
+    #[serde(rename = "MARGIN_CMFUTURE")]
+    MarginToCoinMFutures,
+    #[serde(rename = "CMFUTURE_MARGIN")]
+    CoinMFuturesToMargin,
+    #[serde(rename = "MARGIN_FUNDING")]
+    MarginToFunding,
+    #[serde(rename = "FUNDING_MARGIN")]
+    FundingToMargin,
+    #[serde(rename = "FUNDING_UMFUTURE")]
+    FundingToUsdMFutures,
+    #[serde(rename = "UMFUTURE_FUNDING")]
+    UsdMFuturesToFunding,
+    #[serde(rename = "FUNDING_CMFUTURE")]
+    FundingToCoinMFutures,
+    #[serde(rename = "CMFUTURE_FUNDING")]
+    CoinMFuturesToFunding,
 }
 
 #[derive(Copy, Clone, Serialize, Deserialize, Debug)]
@@ -81,7 +211,7 @@ pub enum Interval {
     OneMonth,
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(super) struct Parameters<'a> {
     pub symbol: Option<&'a str>,
@@ -94,20 +224,20 @@ pub(super) struct Parameters<'a> {
     #[serde(rename = "type")]
     pub order_type: Option<OrderType>,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>,
-    pub price: Option<f64>,
-    pub new_client_order_id: Option<&'a str>,
-    pub stop_price: Option<f64>,
-    pub iceberg_qty: Option<f64>,
+    pub quantity: Option<Number>,
+    pub price: Option<Number>,
+    pub new_client_order_id: Option<Cow<'a, str>>,
+    pub stop_price: Option<Number>,
+    pub iceberg_qty: Option<Number>,
     pub new_order_resp_type: Option<OrderRespType>,
     pub order_id: Option<i64>,
     pub orig_client_order_id: Option<&'a str>,
-    pub list_client_order_id: Option<&'a str>,
+    pub list_client_order_id: Option<Cow<'a, str>>,
     pub limit_client_order_id: Option<&'a str>,
     pub stop_client_order_id: Option<&'a str>,
-    pub limit_iceberg_qty: Option<f64>,
-    pub stop_iceberg_qty: Option<f64>,
-    pub stop_limit_price: Option<f64>,
+    pub limit_iceberg_qty: Option<Number>,
+    pub stop_iceberg_qty: Option<Number>,
+    pub stop_limit_price: Option<Number>,
     pub stop_limit_time_in_force: Option<TimeInForce>,
     pub order_list_id: Option<i64>,
     pub listen_key: Option<&'a str>,
@@ -120,22 +250,75 @@ pub(super) struct Parameters<'a> {
     pub page: Option<usize>,
     pub from_email: Option<&'a str>,
     pub to_email: Option<&'a str>,
-    pub amount: Option<f64>,
+    pub is_freeze: Option<bool>,
+    pub amount: Option<Number>,
+    pub position_side: Option<PositionSide>,
+    pub reduce_only: Option<bool>,
+    pub close_position: Option<bool>,
+    pub working_type: Option<WorkingType>,
+    pub leverage: Option<u32>,
+    pub margin_type: Option<MarginType>,
+    pub income_type: Option<IncomeType>,
+    pub pair: Option<&'a str>,
+    pub margin_asset: Option<&'a str>,
+    #[serde(serialize_with = "upper_bool")]
+    pub is_isolated: Option<bool>,
+    pub isolated_symbol: Option<&'a str>,
+    pub side_effect_type: Option<SideEffectType>,
+    pub current: Option<usize>,
+    pub size: Option<usize>,
+    pub coin: Option<&'a str>,
+    pub network: Option<&'a str>,
+    #[serde(rename = "type")]
+    pub transfer_type: Option<TransferType>,
+    #[serde(rename = "type")]
+    pub loan_type: Option<LoanType>,
+    pub from_account_type: Option<&'a str>,
+    pub to_account_type: Option<&'a str>,
     pub recv_window: Option<usize>,
     pub timestamp: Option<i64>,
     pub signature: Option<String>,
 }
 
+/// Margin endpoints take booleans as `TRUE` or `FALSE`.
+fn upper_bool<S: Serializer>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(true) => serializer.serialize_str("TRUE"),
+        Some(false) => serializer.serialize_str("FALSE"),
+        None => serializer.serialize_none(),
+    }
+}
+
 impl<'a> Parameters<'a> {
-    pub fn sign<T: Into<String>>(&mut self, secret: T) -> crate::error::Result<&Self> {
-        self.timestamp = Some(Utc::now().timestamp_millis());
+    pub fn sign(&mut self, signer: &dyn Signer) -> crate::error::Result<&Self> {
+        if self.timestamp.is_none() {
+            self.timestamp = Some(Utc::now().timestamp_millis());
+        }
 
         let message = serde_urlencoded::to_string(&self)?;
-        let mut mac = HmacSha256::new_varkey(secret.into().as_bytes())?;
-        mac.update(message.as_bytes());
-        let signature = mac.finalize();
-
-        self.signature = Some(hex::encode(signature.into_bytes()));
+        self.signature = Some(signer.sign(message.as_bytes())?);
         Ok(self)
     }
+    /// Parameters of a WebSocket API request, sorted by name; signed when a signer is given.
+    pub fn ws_params(&mut self, api_key: Option<&str>, signer: Option<&dyn Signer>) -> crate::error::Result<BTreeMap<String, String>> {
+        if signer.is_some() && self.timestamp.is_none() {
+            self.timestamp = Some(Utc::now().timestamp_millis());
+        }
+
+        let query = serde_urlencoded::to_string(&self)?;
+        let mut params: BTreeMap<_, _> = form_urlencoded::parse(query.as_bytes()).into_owned().collect();
+
+        if let Some(api_key) = api_key {
+            params.insert("apiKey".into(), api_key.into());
+        }
+        if let Some(signer) = signer {
+            let message = params
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect::<Vec<_>>()
+                .join("&");
+            params.insert("signature".into(), signer.sign(message.as_bytes())?);
+        }
+        Ok(params)
+    }
 }
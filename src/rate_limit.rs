@@ -0,0 +1,479 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Request, StatusCode};
+use url::form_urlencoded;
+use tokio::time::sleep;
+use log::warn;
+
+use crate::error::RateLimitError;
+use crate::model::{RateLimit, RateLimitInterval, RateLimitType};
+
+/// Wait after a 429 response without a `Retry-After` header.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+/// Ban assumed after a 418 response without a `Retry-After` header.
+const DEFAULT_BAN: Duration = Duration::from_secs(2 * 60);
+
+/// Binance API a request is counted against; each has limits of its own.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RestApi {
+    /// `/api`, including the WebSocket API.
+    Spot,
+    /// `/sapi`: wallet, margin and sub-account endpoints.
+    Sapi,
+    /// `/fapi`: USDⓈ-M futures.
+    UsdMFutures,
+    /// `/dapi`: COIN-M futures.
+    CoinMFutures,
+}
+
+impl RestApi {
+    /// API of a request path.
+    fn of(path: &str) -> Self {
+        match path.split('/').nth(1) {
+            Some("sapi") => RestApi::Sapi,
+            Some("fapi") => RestApi::UsdMFutures,
+            Some("dapi") => RestApi::CoinMFutures,
+            _ => RestApi::Spot,
+        }
+    }
+
+    /// Default limits as documented by Binance.
+    fn default_limits(self) -> Vec<RateLimit> {
+        let limit = |rate_limit_type, interval, interval_num, limit| RateLimit {
+            rate_limit_type,
+            interval,
+            interval_num,
+            limit,
+        };
+
+        match self {
+            RestApi::Spot => vec![
+                limit(RateLimitType::RequestWeight, RateLimitInterval::Minute, 1, 1200),
+                limit(RateLimitType::Orders, RateLimitInterval::Second, 10, 50),
+                limit(RateLimitType::Orders, RateLimitInterval::Day, 1, 160_000),
+            ],
+            RestApi::Sapi => vec![
+                limit(RateLimitType::RequestWeight, RateLimitInterval::Minute, 1, 12_000),
+            ],
+            RestApi::UsdMFutures => vec![
+                limit(RateLimitType::RequestWeight, RateLimitInterval::Minute, 1, 2400),
+                limit(RateLimitType::Orders, RateLimitInterval::Second, 10, 300),
+                limit(RateLimitType::Orders, RateLimitInterval::Minute, 1, 1200),
+            ],
+            RestApi::CoinMFutures => vec![
+                limit(RateLimitType::RequestWeight, RateLimitInterval::Minute, 1, 2400),
+                limit(RateLimitType::Orders, RateLimitInterval::Minute, 1, 1200),
+            ],
+        }
+    }
+
+    /// Prefixes of the response headers reporting the used weight and order count.
+    fn headers(self) -> (&'static str, &'static str) {
+        match self {
+            RestApi::Sapi => ("x-sapi-used-ip-weight-", "x-mbx-order-count-"),
+            _ => ("x-mbx-used-weight-", "x-mbx-order-count-"),
+        }
+    }
+}
+
+/// Request weight and order count limiter.
+///
+/// Requests are delayed before they would exceed a limit, usage is kept in
+/// line with the `X-MBX-USED-WEIGHT-*`, `X-SAPI-USED-IP-WEIGHT-*` and
+/// `X-MBX-ORDER-COUNT-*` response headers, requests wait for `Retry-After`
+/// after a 429 response, and fail without being sent while the IP is banned
+/// after a 418 response.
+///
+/// Weight is counted per host and [`RestApi`], and orders per API key.
+/// Cloning is cheap; clones share their usage.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    state: Arc<Mutex<State>>,
+}
+
+impl RateLimiter {
+    /// New limiter with the default Binance limits of each API, e.g. for the
+    /// spot API 1200 weight per minute, 50 orders per 10 seconds and 160000
+    /// orders per day.
+    pub fn new() -> Self {
+        let limits = [RestApi::Spot, RestApi::Sapi, RestApi::UsdMFutures, RestApi::CoinMFutures]
+            .iter()
+            .map(|api| (*api, api.default_limits()))
+            .collect();
+
+        let state = State { limits, ..State::default() };
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+    /// New limiter enforcing the given limits on the spot API, and the default ones on the others.
+    pub fn with_limits(limits: &[RateLimit]) -> Self {
+        let limiter = Self::new();
+        limiter.set_limits(limits);
+        limiter
+    }
+    /// The limiter shared by every client connected to `host`; used by default.
+    pub fn shared(host: &str) -> Self {
+        static SHARED: OnceLock<Mutex<HashMap<String, RateLimiter>>> = OnceLock::new();
+
+        SHARED
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .entry(host.to_string())
+            .or_default()
+            .clone()
+    }
+    /// Replace the limits enforced on the spot API, e.g. with `ExchangeInfo::rate_limits`.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{GeneralClient, RateLimiter, BINANCE_US_URL};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = GeneralClient::connect(BINANCE_US_URL)?;
+    ///     let info = client.get_exchange_info().exchange_info().await?;
+    ///
+    ///     RateLimiter::shared("api.binance.us").set_limits(&info.rate_limits);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_limits(&self, limits: &[RateLimit]) {
+        self.set_api_limits(RestApi::Spot, limits);
+    }
+    /// Replace the limits enforced on one API, e.g. with the rate limits of the futures exchange info.
+    pub fn set_api_limits(&self, api: RestApi, limits: &[RateLimit]) {
+        self.state.lock().unwrap().limits.insert(api, limits.to_vec());
+    }
+    /// Weight used on the spot API in the current minute, as last known.
+    pub fn used_weight(&self) -> u32 {
+        self.used_api_weight(RestApi::Spot)
+    }
+    /// Weight used on one API in the current minute, as last known.
+    pub fn used_api_weight(&self, api: RestApi) -> u32 {
+        let state = self.state.lock().unwrap();
+        let interval = interval_millis(RateLimitInterval::Minute, 1);
+        state.used(&(api, RateLimitType::RequestWeight, interval, String::new()), interval)
+    }
+    /// End of the current IP ban, if any.
+    pub fn banned_until(&self) -> Option<Instant> {
+        self.state.lock().unwrap().banned_until.filter(|until| *until > Instant::now())
+    }
+
+    /// Wait until the request fits within every limit and count it.
+    pub(crate) async fn acquire(&self, cost: Cost, api_key: &str) -> Result<(), RateLimitError> {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let now = Instant::now();
+
+                if let Some(until) = state.banned_until.filter(|until| *until > now) {
+                    return Err(RateLimitError::new(until - now));
+                }
+
+                match state.retry_at.filter(|at| *at > now) {
+                    Some(at) => Some(at - now),
+                    None => state.reserve(cost, api_key),
+                }
+            };
+
+            match wait {
+                Some(wait) => {
+                    warn!("Rate limit reached; waiting {:?}", wait);
+                    sleep(wait).await;
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+    /// Update the usage from the headers and status of a response to a request of `cost`.
+    pub(crate) fn record(&self, cost: Cost, status: StatusCode, headers: &HeaderMap, api_key: &str) {
+        let mut state = self.state.lock().unwrap();
+        let (weight_header, order_header) = cost.api.headers();
+
+        for (name, value) in headers {
+            let (rate_limit_type, interval) = if let Some(interval) = name.as_str().strip_prefix(weight_header) {
+                (RateLimitType::RequestWeight, interval)
+            } else if let Some(interval) = name.as_str().strip_prefix(order_header) {
+                (RateLimitType::Orders, interval)
+            } else {
+                continue;
+            };
+
+            let used = value.to_str().ok().and_then(|value| value.parse().ok());
+
+            if let (Some(interval), Some(used)) = (parse_interval(interval), used) {
+                let key = (cost.api, rate_limit_type, interval, account(rate_limit_type, api_key));
+                let window = now_millis() / interval;
+                let usage = state.usage.entry(key).or_default();
+
+                // Requests still in flight are not part of the header yet.
+                usage.used = if usage.window == window { usage.used.max(used) } else { used };
+                usage.window = window;
+            }
+        }
+
+        let retry_after = headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .map(Duration::from_secs);
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = retry_after.unwrap_or(DEFAULT_RETRY_AFTER);
+            warn!("Rate limit exceeded; retrying after {:?}", retry_after);
+            state.retry_at = Some(Instant::now() + retry_after);
+        } else if status == StatusCode::IM_A_TEAPOT {
+            let retry_after = retry_after.unwrap_or(DEFAULT_BAN);
+            warn!("IP banned for {:?}", retry_after);
+            state.banned_until = Some(Instant::now() + retry_after);
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// API and weight of a request and whether it places an order.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Cost {
+    api: RestApi,
+    weight: u32,
+    order: bool,
+}
+
+impl Cost {
+    /// Weight of the endpoint of a request, given its query and form parameters.
+    pub(crate) fn of(request: &Request) -> Self {
+        let url = request.url();
+        let method = request.method().as_str();
+        let path = url.path();
+        let body = request.body().and_then(reqwest::Body::as_bytes).unwrap_or_default();
+        let params: Vec<_> = url.query_pairs().chain(form_urlencoded::parse(body)).collect();
+        let param = |name: &str| params.iter().find(|(key, _)| key == name).map(|(_, value)| value);
+        let symbol = param("symbol").is_some();
+        let limit = |default| param("limit").and_then(|limit| limit.parse().ok()).unwrap_or(default);
+
+        let weight = match (method, path) {
+            (_, "/api/v3/depth") => match limit(100) {
+                0..=100 => 1,
+                101..=500 => 5,
+                501..=1000 => 10,
+                _ => 50,
+            },
+            (_, "/api/v3/exchangeInfo") => 10,
+            (_, "/api/v3/historicalTrades") => 5,
+            (_, "/api/v3/ticker/24hr") => if symbol { 1 } else { 40 },
+            (_, "/api/v3/ticker/price") | (_, "/api/v3/ticker/bookTicker") => if symbol { 1 } else { 2 },
+            ("GET", "/api/v3/order") | ("GET", "/api/v3/orderList") => 2,
+            ("GET", "/api/v3/openOrders") => if symbol { 3 } else { 40 },
+            ("GET", "/api/v3/openOrderList") => 3,
+            (_, "/api/v3/allOrders")
+            | (_, "/api/v3/allOrderList")
+            | (_, "/api/v3/account")
+            | (_, "/api/v3/myTrades") => 10,
+            (_, "/sapi/v1/capital/config/getall")
+            | (_, "/sapi/v1/capital/deposit/address")
+            | (_, "/sapi/v1/capital/withdraw/history")
+            | (_, "/sapi/v1/margin/account")
+            | (_, "/sapi/v1/margin/isolated/account")
+            | ("GET", "/sapi/v1/margin/order") => 10,
+            (_, "/sapi/v1/margin/maxBorrowable") | (_, "/sapi/v1/margin/maxTransferable") => 50,
+            (_, "/sapi/v3/sub-account/assets") => 60,
+            (_, "/fapi/v1/depth") | (_, "/dapi/v1/depth") => match limit(500) {
+                0..=50 => 2,
+                51..=100 => 5,
+                101..=500 => 10,
+                _ => 20,
+            },
+            (_, "/fapi/v1/klines")
+            | (_, "/dapi/v1/klines")
+            | (_, "/dapi/v1/markPriceKlines")
+            | (_, "/dapi/v1/indexPriceKlines") => match limit(500) {
+                0..=99 => 1,
+                100..=499 => 2,
+                500..=1000 => 5,
+                _ => 10,
+            },
+            (_, "/fapi/v1/ticker/24hr") | (_, "/dapi/v1/ticker/24hr") => if symbol { 1 } else { 40 },
+            ("GET", "/fapi/v1/openOrders") => if symbol { 1 } else { 40 },
+            (_, "/fapi/v1/allOrders")
+            | (_, "/fapi/v1/userTrades")
+            | (_, "/fapi/v2/account")
+            | (_, "/fapi/v2/positionRisk")
+            | (_, "/fapi/v2/balance") => 5,
+            (_, "/fapi/v1/income") => 30,
+            (_, "/dapi/v1/account") => 5,
+            (_, "/dapi/v1/income") => 20,
+            _ => 1,
+        };
+        let order = method == "POST" && matches!(path, "/api/v3/order" | "/api/v3/order/oco" | "/fapi/v1/order" | "/dapi/v1/order" | "/sapi/v1/margin/order");
+
+        Self { api: RestApi::of(path), weight, order }
+    }
+
+    fn amount(self, rate_limit_type: RateLimitType) -> u32 {
+        match rate_limit_type {
+            RateLimitType::RequestWeight => self.weight,
+            RateLimitType::Orders => self.order as u32,
+            RateLimitType::RawRequests => 1,
+        }
+    }
+}
+
+/// Usage within the window of a limit; windows are aligned to the clock.
+#[derive(Default, Debug)]
+struct Usage {
+    window: u64,
+    used: u32,
+}
+
+/// API, limit type, interval in milliseconds and the API key for order limits.
+type Key = (RestApi, RateLimitType, u64, String);
+
+#[derive(Default, Debug)]
+struct State {
+    limits: HashMap<RestApi, Vec<RateLimit>>,
+    usage: HashMap<Key, Usage>,
+    retry_at: Option<Instant>,
+    banned_until: Option<Instant>,
+}
+
+impl State {
+    fn used(&self, key: &Key, interval: u64) -> u32 {
+        let window = now_millis() / interval;
+
+        self.usage
+            .get(key)
+            .filter(|usage| usage.window == window)
+            .map_or(0, |usage| usage.used)
+    }
+
+    /// Count the request, or return how long to wait until it fits.
+    fn reserve(&mut self, cost: Cost, api_key: &str) -> Option<Duration> {
+        let now = now_millis();
+        let mut wait = None;
+        let limits = self.limits.get(&cost.api).map_or(&[][..], Vec::as_slice);
+
+        for limit in limits {
+            let amount = cost.amount(limit.rate_limit_type);
+            let interval = interval_millis(limit.interval, limit.interval_num);
+            let key = (cost.api, limit.rate_limit_type, interval, account(limit.rate_limit_type, api_key));
+
+            if amount > 0 && self.used(&key, interval) + amount > limit.limit {
+                let until_next = Duration::from_millis(interval - now % interval);
+                wait = wait.max(Some(until_next));
+            }
+        }
+
+        if wait.is_some() {
+            return wait;
+        }
+
+        for limit in limits {
+            let amount = cost.amount(limit.rate_limit_type);
+            let interval = interval_millis(limit.interval, limit.interval_num);
+            let key = (cost.api, limit.rate_limit_type, interval, account(limit.rate_limit_type, api_key));
+            let usage = self.usage.entry(key).or_default();
+
+            if usage.window != now / interval {
+                *usage = Usage { window: now / interval, used: 0 };
+            }
+            usage.used += amount;
+        }
+
+        None
+    }
+}
+
+/// Orders are limited per account, everything else per IP.
+fn account(rate_limit_type: RateLimitType, api_key: &str) -> String {
+    match rate_limit_type {
+        RateLimitType::Orders => api_key.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn interval_millis(interval: RateLimitInterval, interval_num: u32) -> u64 {
+    let unit = match interval {
+        RateLimitInterval::Second => 1_000,
+        RateLimitInterval::Minute => 60_000,
+        RateLimitInterval::Day => 86_400_000,
+    };
+    unit * u64::from(interval_num.max(1))
+}
+
+/// Interval of a header suffix such as `1m` or `10s`, in milliseconds.
+fn parse_interval(suffix: &str) -> Option<u64> {
+    let (num, unit) = suffix.split_at(suffix.len().checked_sub(1)?);
+    let num = num.parse().ok()?;
+
+    let interval = match unit {
+        "s" => RateLimitInterval::Second,
+        "m" => RateLimitInterval::Minute,
+        "h" => return Some(3_600_000 * u64::from(num)),
+        "d" => RateLimitInterval::Day,
+        _ => return None,
+    };
+    Some(interval_millis(interval, num))
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::{Client, Method};
+
+    fn cost(method: Method, url: &str, body: &str) -> Cost {
+        let request = Client::new().request(method, url).body(body.to_string()).build().unwrap();
+        Cost::of(&request)
+    }
+
+    #[test]
+    fn counts_query_and_body_parameters() {
+        assert_eq!(cost(Method::GET, "https://api.binance.com/api/v3/depth?limit=5000", "").weight, 50);
+        assert_eq!(cost(Method::GET, "https://api.binance.com/api/v3/openOrders", "").weight, 40);
+        assert_eq!(cost(Method::GET, "https://api.binance.com/api/v3/openOrders", "symbol=BNBUSDT").weight, 3);
+    }
+
+    #[test]
+    fn tells_apis_apart() {
+        let order = cost(Method::POST, "https://fapi.binance.com/fapi/v1/order", "symbol=BTCUSDT");
+        assert_eq!(order.api, RestApi::UsdMFutures);
+        assert!(order.order);
+
+        let klines = cost(Method::GET, "https://dapi.binance.com/dapi/v1/markPriceKlines?limit=1500", "");
+        assert_eq!((klines.api, klines.weight), (RestApi::CoinMFutures, 10));
+
+        let account = cost(Method::GET, "https://api.binance.com/sapi/v1/margin/account", "");
+        assert_eq!((account.api, account.weight), (RestApi::Sapi, 10));
+    }
+
+    #[test]
+    fn limits_apis_separately() {
+        let mut state = State::default();
+        state.limits.insert(RestApi::Spot, RestApi::Spot.default_limits());
+        state.limits.insert(RestApi::Sapi, vec![RateLimit {
+            rate_limit_type: RateLimitType::RequestWeight,
+            interval: RateLimitInterval::Minute,
+            interval_num: 1,
+            limit: 10,
+        }]);
+
+        let margin = Cost { api: RestApi::Sapi, weight: 10, order: false };
+        let spot = Cost { api: RestApi::Spot, weight: 10, order: false };
+
+        assert_eq!(state.reserve(margin, ""), None);
+        assert_eq!(state.reserve(spot, ""), None);
+        assert!(state.reserve(margin, "").is_some());
+    }
+}
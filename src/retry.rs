@@ -0,0 +1,92 @@
+use rand::distributions::Alphanumeric;
+use rand::{thread_rng, Rng};
+use reqwest::Request;
+use tokio::time::Duration;
+
+/// How requests failing with a transient error are retried.
+///
+/// Reads and other idempotent requests are sent again after a backoff.
+/// New orders are only sent again once `AccountClient::get_order` confirmed
+/// that the order wasn't placed, looking it up by its client order id; one is
+/// generated when none was given. Orders expecting an `OrderRespType::Full`
+/// response aren't looked up. Other requests are only sent again when they
+/// were refused before being executed, e.g. because of rate limits. Nothing is
+/// retried while the IP is banned after a 418 response.
+///
+/// # Example
+///
+/// ```no_run
+/// use tokio_binance::{AccountClient, RetryPolicy, BINANCE_US_URL};
+/// use tokio::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let policy = RetryPolicy {
+///         max_retries: 5,
+///         initial_delay: Duration::from_millis(200),
+///         ..RetryPolicy::default()
+///     };
+///     let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?
+///         .with_retry_policy(policy);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub initial_delay: Duration,
+    /// Upper bound of the delay between two retries.
+    pub max_delay: Duration,
+    /// Factor applied to the delay after each retry.
+    pub multiplier: f64,
+    /// Whether new orders are retried after reconciliation. Only orders sent with
+    /// `OrderRespType::Ack` or `OrderRespType::Result` are looked up, since the
+    /// order found stands in for the response and has no fills.
+    pub retry_orders: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            retry_orders: true,
+        }
+    }
+}
+
+/// What sending a request again may cause.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Idempotency {
+    /// Has no effect beyond the first request.
+    Safe,
+    /// Places a new order, which can be looked up by its client order id.
+    NewOrder,
+    /// Places a new order list, which can be looked up by its list client order id.
+    NewOrderList,
+    /// May have an effect each time it is sent.
+    Unsafe,
+}
+
+impl Idempotency {
+    pub(crate) fn of(request: &Request) -> Self {
+        let path = request.url().path();
+
+        match request.method().as_str() {
+            "GET" | "PUT" => Idempotency::Safe,
+            "POST" if path.ends_with("/order/test") => Idempotency::Safe,
+            "POST" if matches!(path, "/api/v3/order" | "/fapi/v1/order" | "/dapi/v1/order" | "/sapi/v1/margin/order") => Idempotency::NewOrder,
+            "POST" if path == "/api/v3/order/oco" => Idempotency::NewOrderList,
+            _ => Idempotency::Unsafe,
+        }
+    }
+}
+
+/// Client order id used to look up a new order that may have been placed.
+pub(crate) fn client_order_id() -> String {
+    thread_rng().sample_iter(&Alphanumeric).take(22).map(char::from).collect()
+}
@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::client::GeneralClient;
+use crate::error::ValidationError;
+use crate::model::{ExchangeInfo, SymbolFilter, SymbolInfo};
+use crate::param::{Number, OrderType, Parameters};
+
+/// What to do with an order that is off the tick or step size of its symbol.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Fail with a `ValidationError` before sending.
+    Reject,
+    /// Round prices to the nearest tick and quantities down to the step size.
+    /// Orders still out of bounds after rounding are rejected.
+    Round,
+}
+
+/// Trading rules of a symbol, taken from its exchangeInfo filters.
+///
+/// Bounds that Binance disables by sending zero are `None`.
+#[derive(Clone, Debug, Default)]
+pub struct SymbolRules {
+    pub symbol: String,
+    pub min_price: Option<Number>,
+    pub max_price: Option<Number>,
+    pub tick_size: Option<Number>,
+    pub min_qty: Option<Number>,
+    pub max_qty: Option<Number>,
+    pub step_size: Option<Number>,
+    pub market_min_qty: Option<Number>,
+    pub market_max_qty: Option<Number>,
+    pub market_step_size: Option<Number>,
+    pub min_notional: Option<Number>,
+    pub max_notional: Option<Number>,
+    /// Bounds of the price relative to the average price, from `PERCENT_PRICE`.
+    pub multiplier_up: Option<Number>,
+    pub multiplier_down: Option<Number>,
+    /// Average price the multipliers apply to; the multipliers aren't
+    /// checked until it is set with `ExchangeRules::set_average_price`.
+    pub average_price: Option<Number>,
+}
+
+impl From<&SymbolInfo> for SymbolRules {
+    fn from(info: &SymbolInfo) -> Self {
+        let mut rules = SymbolRules { symbol: info.symbol.clone(), ..SymbolRules::default() };
+
+        for filter in &info.filters {
+            match *filter {
+                SymbolFilter::PriceFilter { min_price, max_price, tick_size } => {
+                    rules.min_price = enabled(min_price);
+                    rules.max_price = enabled(max_price);
+                    rules.tick_size = enabled(tick_size);
+                }
+                SymbolFilter::LotSize { min_qty, max_qty, step_size } => {
+                    rules.min_qty = enabled(min_qty);
+                    rules.max_qty = enabled(max_qty);
+                    rules.step_size = enabled(step_size);
+                }
+                SymbolFilter::MarketLotSize { min_qty, max_qty, step_size } => {
+                    rules.market_min_qty = enabled(min_qty);
+                    rules.market_max_qty = enabled(max_qty);
+                    rules.market_step_size = enabled(step_size);
+                }
+                SymbolFilter::MinNotional { min_notional, .. } => {
+                    rules.min_notional = enabled(min_notional);
+                }
+                SymbolFilter::Notional { min_notional, max_notional, .. } => {
+                    rules.min_notional = enabled(min_notional);
+                    rules.max_notional = enabled(max_notional);
+                }
+                SymbolFilter::PercentPrice { multiplier_up, multiplier_down, .. } => {
+                    rules.multiplier_up = enabled(multiplier_up);
+                    rules.multiplier_down = enabled(multiplier_down);
+                }
+                _ => {}
+            }
+        }
+
+        rules
+    }
+}
+
+impl SymbolRules {
+    /// Check the prices and quantities of an order, rounding them if asked to.
+    ///
+    /// The notional is only checked for orders with a price, since the
+    /// price of a market order isn't known before it fills.
+    pub(crate) fn validate(&self, params: &mut Parameters<'_>, mode: ValidationMode) -> Result<(), ValidationError> {
+        let market = params.order_type == Some(OrderType::Market);
+
+        for (name, price) in [
+            ("price", &mut params.price),
+            ("stop price", &mut params.stop_price),
+            ("stop limit price", &mut params.stop_limit_price),
+        ] {
+            if let Some(price) = price {
+                self.check(name, price, self.min_price, self.max_price, self.tick_size, Rounding::Nearest, mode)?;
+            }
+        }
+
+        let (min_qty, max_qty, step_size) = if market && self.market_step_size.is_some() {
+            (self.market_min_qty, self.market_max_qty, self.market_step_size)
+        } else {
+            (self.min_qty, self.max_qty, self.step_size)
+        };
+
+        if let Some(quantity) = &mut params.quantity {
+            self.check("quantity", quantity, min_qty, max_qty, step_size, Rounding::Down, mode)?;
+        }
+
+        for (name, qty) in [
+            ("iceberg quantity", &mut params.iceberg_qty),
+            ("limit iceberg quantity", &mut params.limit_iceberg_qty),
+            ("stop iceberg quantity", &mut params.stop_iceberg_qty),
+        ] {
+            if let Some(qty) = qty {
+                self.check(name, qty, None, max_qty, step_size, Rounding::Down, mode)?;
+            }
+        }
+
+        if let Some(average) = self.average_price {
+            for price in [params.price, params.stop_limit_price].iter().flatten() {
+                if let Some(up) = self.multiplier_up.filter(|up| *price > average * *up) {
+                    return Err(self.error(format!("price {} is above {} times the average price of {}", price, up, average)));
+                }
+                if let Some(down) = self.multiplier_down.filter(|down| *price < average * *down) {
+                    return Err(self.error(format!("price {} is below {} times the average price of {}", price, down, average)));
+                }
+            }
+        }
+
+        if let Some(quantity) = params.quantity {
+            for price in [params.price, params.stop_limit_price].iter().flatten() {
+                let notional = *price * quantity;
+
+                if let Some(min) = self.min_notional.filter(|min| notional < *min) {
+                    return Err(self.error(format!("notional {} is below the minimum of {}", notional, min)));
+                }
+                if let Some(max) = self.max_notional.filter(|max| notional > *max) {
+                    return Err(self.error(format!("notional {} is above the maximum of {}", notional, max)));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check(
+        &self,
+        name: &str,
+        value: &mut Number,
+        min: Option<Number>,
+        max: Option<Number>,
+        step: Option<Number>,
+        rounding: Rounding,
+        mode: ValidationMode,
+    ) -> Result<(), ValidationError> {
+        if let Some(step) = step {
+            let aligned = align(*value, step, rounding);
+
+            if aligned != *value {
+                match mode {
+                    ValidationMode::Reject => {
+                        return Err(self.error(format!("{} {} is not a multiple of {}", name, value, step)));
+                    }
+                    ValidationMode::Round => *value = aligned,
+                }
+            }
+        }
+        if let Some(min) = min.filter(|min| *value < *min) {
+            return Err(self.error(format!("{} {} is below the minimum of {}", name, value, min)));
+        }
+        if let Some(max) = max.filter(|max| *value > *max) {
+            return Err(self.error(format!("{} {} is above the maximum of {}", name, value, max)));
+        }
+        Ok(())
+    }
+
+    fn error(&self, message: String) -> ValidationError {
+        ValidationError::new(self.symbol.as_str(), message)
+    }
+}
+
+/// Trading rules of every symbol of the exchange.
+///
+/// Cloning is cheap; the rules are shared between clones.
+#[derive(Clone, Debug)]
+pub struct ExchangeRules {
+    symbols: Arc<HashMap<String, SymbolRules>>,
+    fetched_at: Instant,
+}
+
+impl ExchangeRules {
+    /// Fetch the rules from `GeneralClient::get_exchange_info`.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{AccountClient, ExchangeRules, GeneralClient, ValidationMode, BINANCE_US_URL};
+    /// use tokio_binance::Side::Buy;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let rules = ExchangeRules::fetch(&GeneralClient::connect(BINANCE_US_URL)?).await?;
+    ///     let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?;
+    ///
+    ///     let order = client
+    ///         .place_limit_order("BNBUSDT", Buy, "20.004".parse()?, "5.009".parse()?, true)
+    ///         // sent as a price of 20.00 and a quantity of 5.00 given a 0.01 tick and step size.
+    ///         .with_validation(&rules, ValidationMode::Round)
+    ///         .result()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn fetch(client: &GeneralClient) -> crate::error::Result<Self> {
+        Ok(client.get_exchange_info().exchange_info().await?.into())
+    }
+    /// Replace the rules with the current ones.
+    pub async fn refresh(&mut self, client: &GeneralClient) -> crate::error::Result<()> {
+        *self = Self::fetch(client).await?;
+        Ok(())
+    }
+    /// Rules of a symbol.
+    pub fn get(&self, symbol: &str) -> Option<&SymbolRules> {
+        self.symbols.get(symbol)
+    }
+    /// Set the average price of a symbol, which `PERCENT_PRICE` bounds prices by,
+    /// until the rules are refreshed.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{ExchangeRules, GeneralClient, MarketDataClient, BINANCE_US_URL};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut rules = ExchangeRules::fetch(&GeneralClient::connect(BINANCE_US_URL)?).await?;
+    ///     let client = MarketDataClient::connect("<api-key>", BINANCE_US_URL)?;
+    ///
+    ///     let average = client.get_average_price("BNBUSDT").avg_price().await?;
+    ///     rules.set_average_price("BNBUSDT", average.price);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_average_price(&mut self, symbol: &str, price: Number) {
+        if let Some(rules) = Arc::make_mut(&mut self.symbols).get_mut(symbol) {
+            rules.average_price = Some(price);
+        }
+    }
+    /// Time since the rules were fetched.
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed()
+    }
+
+    pub(crate) fn validate(&self, params: &mut Parameters<'_>, mode: ValidationMode) -> Result<(), ValidationError> {
+        let symbol = params.symbol.unwrap_or_default();
+
+        match self.get(symbol) {
+            Some(rules) => rules.validate(params, mode),
+            None => Err(ValidationError::new(symbol, "no trading rules for symbol")),
+        }
+    }
+}
+
+impl From<ExchangeInfo> for ExchangeRules {
+    fn from(info: ExchangeInfo) -> Self {
+        let symbols = info
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.symbol.clone(), SymbolRules::from(symbol)))
+            .collect();
+
+        Self { symbols: Arc::new(symbols), fetched_at: Instant::now() }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Rounding {
+    Nearest,
+    Down,
+}
+
+fn enabled(value: Number) -> Option<Number> {
+    Some(value).filter(|value| *value != Number::default())
+}
+
+/// `value` moved onto the grid of `step`.
+#[cfg(not(feature = "decimal"))]
+fn align(value: Number, step: Number, rounding: Rounding) -> Number {
+    let steps = value / step;
+    // Absorb representation errors so values already on the grid stay there.
+    let steps = match rounding {
+        _ if (steps - steps.round()).abs() < 1e-9 => steps.round(),
+        Rounding::Nearest => steps.round(),
+        Rounding::Down => steps.floor(),
+    };
+    let decimals = step.to_string().split('.').nth(1).map_or(0, str::len);
+
+    format!("{:.*}", decimals, steps * step).parse().unwrap_or(value)
+}
+
+/// `value` moved onto the grid of `step`.
+#[cfg(feature = "decimal")]
+fn align(value: Number, step: Number, rounding: Rounding) -> Number {
+    let steps = value / step;
+    let steps = match rounding {
+        Rounding::Nearest => steps.round(),
+        Rounding::Down => steps.floor(),
+    };
+
+    (steps * step).normalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::param::Side;
+
+    fn number(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    fn rules() -> SymbolRules {
+        SymbolRules {
+            symbol: "BNBUSDT".into(),
+            min_price: Some(number("0.01")),
+            max_price: Some(number("1000")),
+            tick_size: Some(number("0.01")),
+            min_qty: Some(number("0.001")),
+            max_qty: Some(number("100")),
+            step_size: Some(number("0.001")),
+            min_notional: Some(number("10")),
+            multiplier_up: Some(number("5")),
+            multiplier_down: Some(number("0.2")),
+            ..SymbolRules::default()
+        }
+    }
+
+    fn order<'a>(price: &str, quantity: &str) -> Parameters<'a> {
+        Parameters {
+            symbol: Some("BNBUSDT"),
+            side: Some(Side::Buy),
+            order_type: Some(OrderType::Limit),
+            price: Some(number(price)),
+            quantity: Some(number(quantity)),
+            ..Parameters::default()
+        }
+    }
+
+    #[test]
+    fn align_keeps_values_on_the_grid() {
+        for (value, step) in [("0.3", "0.1"), ("20.10", "0.01"), ("1.005", "0.005"), ("0.00000123", "0.00000001"), ("5", "1")] {
+            assert_eq!(align(number(value), number(step), Rounding::Nearest), number(value));
+            assert_eq!(align(number(value), number(step), Rounding::Down), number(value));
+        }
+    }
+
+    #[test]
+    fn align_rounds_onto_the_grid() {
+        assert_eq!(align(number("20.004"), number("0.01"), Rounding::Nearest), number("20.00"));
+        assert_eq!(align(number("20.006"), number("0.01"), Rounding::Nearest), number("20.01"));
+        assert_eq!(align(number("5.009"), number("0.01"), Rounding::Down), number("5.00"));
+        assert_eq!(align(number("0.29999"), number("0.1"), Rounding::Down), number("0.2"));
+        assert_eq!(align(number("123.456"), number("10"), Rounding::Down), number("120"));
+    }
+
+    #[test]
+    fn reject_mode_refuses_off_grid_values() {
+        let mut params = order("20.004", "1");
+        assert!(rules().validate(&mut params, ValidationMode::Reject).is_err());
+
+        let mut params = order("20.00", "1");
+        assert!(rules().validate(&mut params, ValidationMode::Reject).is_ok());
+    }
+
+    #[test]
+    fn round_mode_rounds_prices_and_quantities() {
+        let mut params = order("20.004", "1.0009");
+        rules().validate(&mut params, ValidationMode::Round).unwrap();
+
+        assert_eq!(params.price, Some(number("20.00")));
+        assert_eq!(params.quantity, Some(number("1.000")));
+    }
+
+    #[test]
+    fn checks_bounds_and_notional() {
+        assert!(rules().validate(&mut order("1000.01", "1"), ValidationMode::Round).is_err());
+        assert!(rules().validate(&mut order("20", "1000"), ValidationMode::Round).is_err());
+        assert!(rules().validate(&mut order("20", "0.4"), ValidationMode::Round).is_err());
+        assert!(rules().validate(&mut order("20", "0.5"), ValidationMode::Round).is_ok());
+    }
+
+    #[test]
+    fn percent_price_needs_the_average_price() {
+        let mut rules = rules();
+        assert!(rules.validate(&mut order("500", "1"), ValidationMode::Reject).is_ok());
+
+        rules.average_price = Some(number("20"));
+        assert!(rules.validate(&mut order("100", "1"), ValidationMode::Reject).is_ok());
+        assert!(rules.validate(&mut order("100.01", "1"), ValidationMode::Reject).is_err());
+        assert!(rules.validate(&mut order("4", "5"), ValidationMode::Reject).is_ok());
+        assert!(rules.validate(&mut order("3.99", "5"), ValidationMode::Reject).is_err());
+    }
+}
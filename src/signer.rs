@@ -0,0 +1,160 @@
+use std::fmt;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use crate::credentials::Secret;
+#[cfg(feature = "asymmetric-keys")]
+use openssl::pkey::{Id, PKey, Private};
+#[cfg(feature = "asymmetric-keys")]
+use openssl::hash::MessageDigest;
+#[cfg(feature = "asymmetric-keys")]
+use crate::error::{Error, Kind};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs the query of requests to signed endpoints.
+///
+/// Implemented by [`HmacSigner`] for secret keys and, with the `asymmetric-keys`
+/// feature enabled, by [`RsaSigner`] and [`Ed25519Signer`] for key pairs whose
+/// public key was registered with Binance.
+pub trait Signer: Send + Sync {
+    /// Signature of `payload`, encoded as Binance expects it for this kind of key.
+    fn sign(&self, payload: &[u8]) -> crate::error::Result<String>;
+}
+
+/// HMAC-SHA256 signatures with a secret key, hex encoded; used by `connect`.
+/// # Example
+///
+/// ```
+/// use tokio_binance::{HmacSigner, Signer};
+///
+/// let signer = HmacSigner::new("NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j");
+/// let query = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+///
+/// assert_eq!(
+///     signer.sign(query.as_bytes()).unwrap(),
+///     "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+/// );
+/// ```
+pub struct HmacSigner {
+    secret_key: Secret,
+}
+
+impl HmacSigner {
+    pub fn new<S: Into<String>>(secret_key: S) -> Self {
+        Self::from_secret(Secret::new(secret_key))
+    }
+
+    pub(crate) fn from_secret(secret_key: Secret) -> Self {
+        Self { secret_key }
+    }
+}
+
+impl fmt::Debug for HmacSigner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HmacSigner").field("secret_key", &self.secret_key).finish()
+    }
+}
+
+impl Signer for HmacSigner {
+    fn sign(&self, payload: &[u8]) -> crate::error::Result<String> {
+        let mut mac = HmacSha256::new_varkey(self.secret_key.expose().as_bytes())?;
+        mac.update(payload);
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
+/// RSASSA-PKCS1-v1_5 SHA-256 signatures with an RSA private key, base64 encoded.
+/// # Example
+///
+/// ```no_run
+/// use tokio_binance::{AccountClient, RsaSigner, BINANCE_US_URL};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let signer = RsaSigner::from_pem(&std::fs::read("private_key.pem")?)?;
+///     let client = AccountClient::connect_with_signer("<api-key>", signer, BINANCE_US_URL)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "asymmetric-keys")]
+#[derive(Clone)]
+pub struct RsaSigner {
+    key: PKey<Private>,
+}
+
+#[cfg(feature = "asymmetric-keys")]
+impl RsaSigner {
+    /// Load a PKCS#8 PEM encoded RSA private key.
+    pub fn from_pem(pem: &[u8]) -> crate::error::Result<Self> {
+        Ok(Self { key: private_key(pem, Id::RSA, "RSA")? })
+    }
+}
+
+#[cfg(feature = "asymmetric-keys")]
+impl fmt::Debug for RsaSigner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RsaSigner").field("key", &"[REDACTED]").finish()
+    }
+}
+
+#[cfg(feature = "asymmetric-keys")]
+impl Signer for RsaSigner {
+    fn sign(&self, payload: &[u8]) -> crate::error::Result<String> {
+        let mut signer = openssl::sign::Signer::new(MessageDigest::sha256(), &self.key)?;
+        signer.update(payload)?;
+        Ok(base64::encode(signer.sign_to_vec()?))
+    }
+}
+
+/// Ed25519 signatures with an Ed25519 private key, base64 encoded.
+/// # Example
+///
+/// ```no_run
+/// use tokio_binance::{AccountClient, Ed25519Signer, BINANCE_US_URL};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let signer = Ed25519Signer::from_pem(&std::fs::read("private_key.pem")?)?;
+///     let client = AccountClient::connect_with_signer("<api-key>", signer, BINANCE_US_URL)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "asymmetric-keys")]
+#[derive(Clone)]
+pub struct Ed25519Signer {
+    key: PKey<Private>,
+}
+
+#[cfg(feature = "asymmetric-keys")]
+impl Ed25519Signer {
+    /// Load a PKCS#8 PEM encoded Ed25519 private key.
+    pub fn from_pem(pem: &[u8]) -> crate::error::Result<Self> {
+        Ok(Self { key: private_key(pem, Id::ED25519, "Ed25519")? })
+    }
+}
+
+#[cfg(feature = "asymmetric-keys")]
+impl fmt::Debug for Ed25519Signer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Ed25519Signer").field("key", &"[REDACTED]").finish()
+    }
+}
+
+#[cfg(feature = "asymmetric-keys")]
+impl Signer for Ed25519Signer {
+    fn sign(&self, payload: &[u8]) -> crate::error::Result<String> {
+        let mut signer = openssl::sign::Signer::new_without_digest(&self.key)?;
+        Ok(base64::encode(signer.sign_oneshot_to_vec(payload)?))
+    }
+}
+
+#[cfg(feature = "asymmetric-keys")]
+fn private_key(pem: &[u8], id: Id, name: &str) -> crate::error::Result<PKey<Private>> {
+    let key = PKey::private_key_from_pem(pem)?;
+
+    if key.id() == id {
+        Ok(key)
+    } else {
+        Err(Error::new(Kind::Openssl, Some(format!("not an {} private key", name))))
+    }
+}
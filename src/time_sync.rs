@@ -0,0 +1,157 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use chrono::Utc;
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Duration, Instant};
+use log::warn;
+
+use crate::client::GeneralClient;
+
+/// Default time between two synchronizations with the server.
+pub const DEFAULT_TIME_SYNC_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// Server time requests made per synchronization; the fastest one is kept.
+const SAMPLES: usize = 3;
+
+/// Estimate of the server clock relative to ours.
+#[derive(Copy, Clone, Debug)]
+struct Estimate {
+    offset: i64,
+    latency: Duration,
+    synced_at: Instant,
+}
+
+/// Server clock used to stamp signed requests.
+///
+/// The offset to the server clock is estimated from `GeneralClient::get_server_time`,
+/// compensating for half of the round trip. It is measured on first use, then
+/// refreshed in the background every sync interval until every clone is dropped.
+/// The requests go through the rate limiter and host failover of the client.
+/// Cloning is cheap; clones share their estimate.
+#[derive(Clone)]
+pub struct TimeSync {
+    client: Arc<GeneralClient>,
+    interval: Duration,
+    resync_on_error: bool,
+    estimate: Arc<Mutex<Option<Estimate>>>,
+    refreshing: Arc<AtomicBool>,
+}
+
+impl TimeSync {
+    /// Synchronize with the server of `client` every 10 minutes and resync on -1021 errors.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{AccountClient, GeneralClient, TimeSync, BINANCE_US_URL};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let time_sync = TimeSync::new(GeneralClient::connect(BINANCE_US_URL)?);
+    ///     let client = AccountClient::connect("<api-key>", "<secret-key>", BINANCE_US_URL)?
+    ///         .with_time_sync(time_sync);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(client: GeneralClient) -> Self {
+        Self {
+            client: Arc::new(client),
+            interval: DEFAULT_TIME_SYNC_INTERVAL,
+            resync_on_error: true,
+            estimate: Arc::new(Mutex::new(None)),
+            refreshing: Arc::new(AtomicBool::new(false)),
+        }
+    }
+    /// Change how often the clock is synchronized.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+    /// Whether to resync and retry once when a request fails with -1021
+    /// "Timestamp for this request is outside of the recvWindow"; enabled by default.
+    pub fn with_resync_on_error(mut self, resync_on_error: bool) -> Self {
+        self.resync_on_error = resync_on_error;
+        self
+    }
+    /// Synchronize with the server now.
+    pub async fn sync(&self) -> crate::error::Result<()> {
+        let mut estimate = self.estimate.lock().await;
+        *estimate = Some(measure(&self.client).await?);
+        Ok(())
+    }
+    /// Milliseconds to add to our clock to get the server time, if synchronized.
+    pub async fn offset(&self) -> Option<i64> {
+        self.estimate.lock().await.map(|estimate| estimate.offset)
+    }
+    /// Round trip of the server time request used for the current estimate.
+    pub async fn latency(&self) -> Option<Duration> {
+        self.estimate.lock().await.map(|estimate| estimate.latency)
+    }
+    /// Current server time in milliseconds; synchronizes first if there is no estimate yet,
+    /// or if the background refresh kept failing.
+    pub async fn timestamp(&self) -> crate::error::Result<i64> {
+        self.spawn_refresh();
+        let mut estimate = self.estimate.lock().await;
+
+        let offset = match *estimate {
+            Some(current) if current.synced_at.elapsed() < 2 * self.interval => current.offset,
+            _ => {
+                let fresh = measure(&self.client).await?;
+                *estimate = Some(fresh);
+                fresh.offset
+            }
+        };
+
+        Ok(Utc::now().timestamp_millis() + offset)
+    }
+
+    pub(crate) fn resyncs_on_error(&self) -> bool {
+        self.resync_on_error
+    }
+
+    /// Refresh the estimate every interval, until every clone is dropped.
+    fn spawn_refresh(&self) {
+        if self.refreshing.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let client = self.client.clone();
+        let interval = self.interval;
+        let estimate = Arc::downgrade(&self.estimate);
+
+        tokio::spawn(async move {
+            loop {
+                sleep(interval).await;
+
+                let estimate = match estimate.upgrade() {
+                    Some(estimate) => estimate,
+                    None => return,
+                };
+
+                match measure(&client).await {
+                    Ok(fresh) => *estimate.lock().await = Some(fresh),
+                    Err(e) => warn!("Failed to synchronize the time: {}", e),
+                }
+            }
+        });
+    }
+}
+
+async fn measure(client: &GeneralClient) -> crate::error::Result<Estimate> {
+    let mut best: Option<Estimate> = None;
+
+    for _ in 0..SAMPLES {
+        let sent = Instant::now();
+        let local = Utc::now().timestamp_millis();
+        // Boxed since requests await the time sync of their own client.
+        let server = Box::pin(client.get_server_time().server_time()).await?.server_time;
+        let latency = sent.elapsed();
+
+        // The server stamped its time about halfway through the round trip.
+        let offset = server - (local + latency.as_millis() as i64 / 2);
+
+        if best.map_or(true, |best| latency < best.latency) {
+            best = Some(Estimate { offset, latency, synced_at: Instant::now() });
+        }
+    }
+
+    Ok(best.expect("at least one sample"))
+}
@@ -17,6 +17,7 @@ pub trait LimitIcebergQty {}
 pub trait StopIcebergQty {}
 pub trait StopLimitPrice {}
 pub trait RecvWindow {}
+pub trait Validation {}
 
 pub trait LimitMaker {}
 pub trait LimitOrderStopPrice {}
@@ -29,6 +30,22 @@ pub trait Status {}
 pub trait Email {}
 pub trait Page {}
 
+pub trait PositionSide {}
+pub trait ReduceOnly {}
+pub trait ClosePosition {}
+pub trait WorkingType {}
+pub trait FuturesStopPrice {}
+pub trait IncomeType {}
+pub trait Pair {}
+pub trait MarginAsset {}
+pub trait Isolated {}
+pub trait IsolatedSymbol {}
+pub trait SideEffectType {}
+pub trait Current {}
+pub trait Size {}
+pub trait Coin {}
+pub trait Network {}
+
 pub struct PingParams;
 pub struct TimeParams;
 pub struct ExchangeInfoParams;
@@ -72,17 +89,20 @@ impl NewClientOrderId for LimitOrderParams {}
 impl IcebergQty for LimitOrderParams {}
 impl NewOrderRespType for LimitOrderParams {}
 impl RecvWindow for LimitOrderParams {}
+impl Validation for LimitOrderParams {}
 
 pub struct LimitMakerOrderParams;
 impl NewClientOrderId for LimitMakerOrderParams {}
 impl NewOrderRespType for LimitMakerOrderParams {}
 impl RecvWindow for LimitMakerOrderParams {}
+impl Validation for LimitMakerOrderParams {}
 
 pub struct MarketOrderParams;
 impl MarketOrderStopPrice for MarketOrderParams {}
 impl NewClientOrderId for MarketOrderParams {}
 impl NewOrderRespType for MarketOrderParams {}
 impl RecvWindow for MarketOrderParams {}
+impl Validation for MarketOrderParams {}
 
 pub struct OrderStatusParams;
 impl RecvWindow for OrderStatusParams {}
@@ -111,6 +131,7 @@ impl StopIcebergQty for OcoParams {}
 impl StopLimitPrice for OcoParams {}
 impl NewOrderRespType for OcoParams {}
 impl RecvWindow for OcoParams {}
+impl Validation for OcoParams {}
 
 pub struct CancelOcoParams;
 impl NewClientOrderId for CancelOcoParams {}
@@ -144,26 +165,29 @@ pub struct KeepAliveStreamParams;
 pub struct CloseStreamParams;
 
 pub struct WithdrawParams;
+impl Network for WithdrawParams {}
 impl AddressTag for WithdrawParams {}
 impl Name for WithdrawParams {}
 impl RecvWindow for WithdrawParams {}
 
 pub struct DepositHistoryParams;
-impl Asset for DepositHistoryParams {}
+impl Coin for DepositHistoryParams {}
 impl Status for DepositHistoryParams {}
 impl StartTime for DepositHistoryParams {}
 impl EndTime for DepositHistoryParams {}
+impl Limit for DepositHistoryParams {}
 impl RecvWindow for DepositHistoryParams {}
 
 pub struct WithdrawHistoryParams;
-impl Asset for WithdrawHistoryParams {}
+impl Coin for WithdrawHistoryParams {}
 impl Status for WithdrawHistoryParams {}
 impl StartTime for WithdrawHistoryParams {}
 impl EndTime for WithdrawHistoryParams {}
+impl Limit for WithdrawHistoryParams {}
 impl RecvWindow for WithdrawHistoryParams {}
 
 pub struct DepositAddressParams;
-impl Status for DepositAddressParams {}
+impl Network for DepositAddressParams {}
 impl RecvWindow for DepositAddressParams {}
 
 pub struct AccountStatusParams;
@@ -175,6 +199,8 @@ pub struct ApiStatusParams;
 impl RecvWindow for ApiStatusParams {}
 
 pub struct DustlogParams;
+impl StartTime for DustlogParams {}
+impl EndTime for DustlogParams {}
 impl RecvWindow for DustlogParams {}
 
 pub struct TradeFeeParams;
@@ -182,11 +208,11 @@ impl Symbol for TradeFeeParams {}
 impl RecvWindow for TradeFeeParams {}
 
 pub struct AssetDetailParams;
+impl Asset for AssetDetailParams {}
 impl RecvWindow for AssetDetailParams {}
 
 pub struct SubAccountParams;
 impl Email for SubAccountParams {}
-impl Status for SubAccountParams {}
 impl Page for SubAccountParams {}
 impl Limit for SubAccountParams {}
 impl RecvWindow for SubAccountParams {}
@@ -202,7 +228,6 @@ pub struct TransferSubAccountParams;
 impl RecvWindow for TransferSubAccountParams {}
 
 pub struct SubAccountAssetParams;
-impl Symbol for SubAccountAssetParams {}
 impl RecvWindow for SubAccountAssetParams {}
 
 pub struct DustTransferParams;
@@ -212,4 +237,164 @@ pub struct AssetDividendParams;
 impl Asset for AssetDividendParams {}
 impl StartTime for AssetDividendParams {}
 impl EndTime for AssetDividendParams {}
-impl RecvWindow for AssetDividendParams {}
\ No newline at end of file
+impl RecvWindow for AssetDividendParams {}
+
+pub struct FuturesLimitOrderParams;
+impl TimeInForce for FuturesLimitOrderParams {}
+impl NewClientOrderId for FuturesLimitOrderParams {}
+impl PositionSide for FuturesLimitOrderParams {}
+impl ReduceOnly for FuturesLimitOrderParams {}
+impl WorkingType for FuturesLimitOrderParams {}
+impl FuturesStopPrice for FuturesLimitOrderParams {}
+impl RecvWindow for FuturesLimitOrderParams {}
+
+pub struct FuturesMarketOrderParams;
+impl NewClientOrderId for FuturesMarketOrderParams {}
+impl PositionSide for FuturesMarketOrderParams {}
+impl ReduceOnly for FuturesMarketOrderParams {}
+impl ClosePosition for FuturesMarketOrderParams {}
+impl WorkingType for FuturesMarketOrderParams {}
+impl FuturesStopPrice for FuturesMarketOrderParams {}
+impl RecvWindow for FuturesMarketOrderParams {}
+
+pub struct FuturesOrderStatusParams;
+impl RecvWindow for FuturesOrderStatusParams {}
+
+pub struct FuturesCancelOrderParams;
+impl RecvWindow for FuturesCancelOrderParams {}
+
+pub struct PositionRiskParams;
+impl Symbol for PositionRiskParams {}
+impl RecvWindow for PositionRiskParams {}
+
+pub struct LeverageParams;
+impl RecvWindow for LeverageParams {}
+
+pub struct MarginTypeParams;
+impl RecvWindow for MarginTypeParams {}
+
+pub struct FuturesBalanceParams;
+impl RecvWindow for FuturesBalanceParams {}
+
+pub struct IncomeParams;
+impl Symbol for IncomeParams {}
+impl IncomeType for IncomeParams {}
+impl StartTime for IncomeParams {}
+impl EndTime for IncomeParams {}
+impl Limit for IncomeParams {}
+impl RecvWindow for IncomeParams {}
+
+pub struct FundingRateParams;
+impl Symbol for FundingRateParams {}
+impl StartTime for FundingRateParams {}
+impl EndTime for FundingRateParams {}
+impl Limit for FundingRateParams {}
+
+pub struct DeliveryExchangeInfoParams;
+
+pub struct DeliveryLimitOrderParams;
+impl TimeInForce for DeliveryLimitOrderParams {}
+impl NewClientOrderId for DeliveryLimitOrderParams {}
+impl PositionSide for DeliveryLimitOrderParams {}
+impl ReduceOnly for DeliveryLimitOrderParams {}
+impl WorkingType for DeliveryLimitOrderParams {}
+impl FuturesStopPrice for DeliveryLimitOrderParams {}
+impl RecvWindow for DeliveryLimitOrderParams {}
+
+pub struct DeliveryMarketOrderParams;
+impl NewClientOrderId for DeliveryMarketOrderParams {}
+impl PositionSide for DeliveryMarketOrderParams {}
+impl ReduceOnly for DeliveryMarketOrderParams {}
+impl ClosePosition for DeliveryMarketOrderParams {}
+impl WorkingType for DeliveryMarketOrderParams {}
+impl FuturesStopPrice for DeliveryMarketOrderParams {}
+impl RecvWindow for DeliveryMarketOrderParams {}
+
+pub struct DeliveryOrderStatusParams;
+impl RecvWindow for DeliveryOrderStatusParams {}
+
+pub struct DeliveryCancelOrderParams;
+impl RecvWindow for DeliveryCancelOrderParams {}
+
+pub struct DeliveryPositionParams;
+impl Pair for DeliveryPositionParams {}
+impl MarginAsset for DeliveryPositionParams {}
+impl RecvWindow for DeliveryPositionParams {}
+
+pub struct LeverageBracketParams;
+impl Symbol for LeverageBracketParams {}
+impl RecvWindow for LeverageBracketParams {}
+
+pub struct LoanParams;
+impl RecvWindow for LoanParams {}
+
+pub struct MarginLimitOrderParams;
+impl Isolated for MarginLimitOrderParams {}
+impl SideEffectType for MarginLimitOrderParams {}
+impl TimeInForce for MarginLimitOrderParams {}
+impl LimitOrderStopPrice for MarginLimitOrderParams {}
+impl NewClientOrderId for MarginLimitOrderParams {}
+impl IcebergQty for MarginLimitOrderParams {}
+impl NewOrderRespType for MarginLimitOrderParams {}
+impl RecvWindow for MarginLimitOrderParams {}
+
+pub struct MarginMarketOrderParams;
+impl Isolated for MarginMarketOrderParams {}
+impl SideEffectType for MarginMarketOrderParams {}
+impl MarketOrderStopPrice for MarginMarketOrderParams {}
+impl NewClientOrderId for MarginMarketOrderParams {}
+impl NewOrderRespType for MarginMarketOrderParams {}
+impl RecvWindow for MarginMarketOrderParams {}
+
+pub struct MarginOrderStatusParams;
+impl Isolated for MarginOrderStatusParams {}
+impl RecvWindow for MarginOrderStatusParams {}
+
+pub struct MarginCancelOrderParams;
+impl Isolated for MarginCancelOrderParams {}
+impl NewClientOrderId for MarginCancelOrderParams {}
+impl RecvWindow for MarginCancelOrderParams {}
+
+pub struct MarginAccountParams;
+impl RecvWindow for MarginAccountParams {}
+
+pub struct IsolatedMarginAccountParams;
+impl RecvWindow for IsolatedMarginAccountParams {}
+
+pub struct MaxBorrowableParams;
+impl IsolatedSymbol for MaxBorrowableParams {}
+impl RecvWindow for MaxBorrowableParams {}
+
+pub struct MaxTransferableParams;
+impl IsolatedSymbol for MaxTransferableParams {}
+impl RecvWindow for MaxTransferableParams {}
+
+pub struct InterestHistoryParams;
+impl Asset for InterestHistoryParams {}
+impl IsolatedSymbol for InterestHistoryParams {}
+impl StartTime for InterestHistoryParams {}
+impl EndTime for InterestHistoryParams {}
+impl Current for InterestHistoryParams {}
+impl Size for InterestHistoryParams {}
+impl RecvWindow for InterestHistoryParams {}
+
+pub struct ForceLiquidationParams;
+impl IsolatedSymbol for ForceLiquidationParams {}
+impl StartTime for ForceLiquidationParams {}
+impl EndTime for ForceLiquidationParams {}
+impl Current for ForceLiquidationParams {}
+impl Size for ForceLiquidationParams {}
+impl RecvWindow for ForceLiquidationParams {}
+
+pub struct CoinsParams;
+impl RecvWindow for CoinsParams {}
+
+pub struct UniversalTransferParams;
+impl RecvWindow for UniversalTransferParams {}
+
+pub struct UniversalTransferHistoryParams;
+impl StartTime for UniversalTransferHistoryParams {}
+impl EndTime for UniversalTransferHistoryParams {}
+impl Current for UniversalTransferHistoryParams {}
+impl Size for UniversalTransferHistoryParams {}
+impl RecvWindow for UniversalTransferHistoryParams {}
@@ -0,0 +1,398 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::runtime::Handle;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Duration};
+use log::warn;
+
+use crate::builder::ParamBuilder;
+use crate::client::{DeliveryClient, FuturesClient, UserDataClient};
+use crate::environment::WsUrl;
+use crate::model::{Event, UserDataEvent};
+use crate::types::{CloseStreamParams, KeepAliveStreamParams, StartStreamParams};
+use crate::ws_stream::{Channel, ReconnectPolicy, WebSocketStream};
+
+/// Default time between two keepalive requests; listen keys expire after 60 minutes.
+pub const DEFAULT_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Client managing the listen keys of a [`UserDataStream`]: [`UserDataClient`] for spot,
+/// [`FuturesClient`] and [`DeliveryClient`] for USDⓈ-M and COIN-M futures.
+pub trait ListenKeyClient: Clone + Send + Sync + 'static {
+    /// Start a user data stream, or get the listen key of the active one.
+    fn start_stream(&self) -> ParamBuilder<'_, '_, StartStreamParams>;
+    /// Keepalive the user data stream of `listen_key`.
+    fn keep_alive<'a>(&self, listen_key: &'a str) -> ParamBuilder<'a, '_, KeepAliveStreamParams>;
+    /// Close out the user data stream of `listen_key`.
+    fn close_stream<'a>(&self, listen_key: &'a str) -> ParamBuilder<'a, '_, CloseStreamParams>;
+}
+
+impl ListenKeyClient for UserDataClient {
+    fn start_stream(&self) -> ParamBuilder<'_, '_, StartStreamParams> {
+        UserDataClient::start_stream(self)
+    }
+    fn keep_alive<'a>(&self, listen_key: &'a str) -> ParamBuilder<'a, '_, KeepAliveStreamParams> {
+        UserDataClient::keep_alive(self, listen_key)
+    }
+    fn close_stream<'a>(&self, listen_key: &'a str) -> ParamBuilder<'a, '_, CloseStreamParams> {
+        UserDataClient::close_stream(self, listen_key)
+    }
+}
+
+// Futures accounts have a single listen key, so it isn't sent back.
+impl ListenKeyClient for FuturesClient {
+    fn start_stream(&self) -> ParamBuilder<'_, '_, StartStreamParams> {
+        FuturesClient::start_stream(self)
+    }
+    fn keep_alive<'a>(&self, _: &'a str) -> ParamBuilder<'a, '_, KeepAliveStreamParams> {
+        FuturesClient::keep_alive(self)
+    }
+    fn close_stream<'a>(&self, _: &'a str) -> ParamBuilder<'a, '_, CloseStreamParams> {
+        FuturesClient::close_stream(self)
+    }
+}
+
+impl ListenKeyClient for DeliveryClient {
+    fn start_stream(&self) -> ParamBuilder<'_, '_, StartStreamParams> {
+        DeliveryClient::start_stream(self)
+    }
+    fn keep_alive<'a>(&self, _: &'a str) -> ParamBuilder<'a, '_, KeepAliveStreamParams> {
+        DeliveryClient::keep_alive(self)
+    }
+    fn close_stream<'a>(&self, _: &'a str) -> ParamBuilder<'a, '_, CloseStreamParams> {
+        DeliveryClient::close_stream(self)
+    }
+}
+
+/// User data stream that manages its own listen key.
+///
+/// The listen key is kept alive in the background, and a new one is
+/// obtained whenever it expires or the socket is closed by the server.
+/// The listen key is closed when the stream is shut down or dropped.
+///
+/// Futures user data streams are started with a [`FuturesClient`] or [`DeliveryClient`]:
+/// ```no_run
+/// use tokio_binance::{FuturesClient, Environment, UserDataStream};
+/// use tokio_binance::model::{Event, UserDataEvent};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = FuturesClient::connect("<api-key>", "<secret-key>", Environment::UsdMFutures)?;
+///     let mut stream = UserDataStream::connect(client, Environment::UsdMFutures).await?;
+///
+///     while let Some(event) = stream.event().await? {
+///         if let Event::UserData(UserDataEvent::OrderTradeUpdate(update)) = event {
+///             println!("{} {:?}", update.order.client_order_id, update.order.order_status);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct UserDataStream<C: ListenKeyClient = UserDataClient> {
+    client: C,
+    url: WsUrl,
+    listen_key: String,
+    interval: Duration,
+    expired: Arc<AtomicBool>,
+    keep_alive_failed: Arc<Notify>,
+    keep_alive: Option<JoinHandle<()>>,
+    stream: Option<WebSocketStream>,
+    /// Wait before the next reconnect after the socket failed or closed.
+    backoff: Duration,
+    /// The last reconnect failed; the next call to `event` tries again after the backoff.
+    reconnect_failed: bool,
+}
+
+impl<C: ListenKeyClient> UserDataStream<C> {
+    /// Start a user data stream, keeping the listen key alive every 30 minutes.
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tokio_binance::{UserDataClient, UserDataStream, BINANCE_US_URL, BINANCE_US_WSS_URL};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = UserDataClient::connect("<api-key>", BINANCE_US_URL)?;
+    ///     let mut stream = UserDataStream::connect(client, BINANCE_US_WSS_URL).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn connect<U: Into<WsUrl>>(
+        client: C,
+        url: U,
+    ) -> crate::error::Result<Self> {
+        Self::connect_with_interval(client, url, DEFAULT_KEEP_ALIVE_INTERVAL).await
+    }
+    /// Start a user data stream, keeping the listen key alive on the given interval.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{UserDataClient, UserDataStream, BINANCE_US_URL, BINANCE_US_WSS_URL};
+    /// use tokio::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = UserDataClient::connect("<api-key>", BINANCE_US_URL)?;
+    /// let interval = Duration::from_secs(15 * 60);
+    /// let mut stream = UserDataStream::connect_with_interval(client, BINANCE_US_WSS_URL, interval).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_with_interval<U: Into<WsUrl>>(
+        client: C,
+        url: U,
+        interval: Duration,
+    ) -> crate::error::Result<Self> {
+        let mut stream = Self {
+            client,
+            url: url.into(),
+            listen_key: String::new(),
+            interval,
+            expired: Arc::new(AtomicBool::new(false)),
+            keep_alive_failed: Arc::new(Notify::new()),
+            keep_alive: None,
+            stream: None,
+            backoff: ReconnectPolicy::default().initial_delay,
+            reconnect_failed: false,
+        };
+
+        stream.reconnect().await?;
+        Ok(stream)
+    }
+    /// Get the next event of the user data stream.
+    /// Reconnects with a fresh listen key when the current one expires or the socket closes,
+    /// backing off between reconnects when the socket keeps failing.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{UserDataClient, UserDataStream, BINANCE_US_URL, BINANCE_US_WSS_URL};
+    /// use tokio_binance::model::{Event, UserDataEvent};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = UserDataClient::connect("<api-key>", BINANCE_US_URL)?;
+    /// # let mut stream = UserDataStream::connect(client, BINANCE_US_WSS_URL).await?;
+    /// while let Some(event) = stream.event().await? {
+    ///     if let Event::UserData(UserDataEvent::ExecutionReport(report)) = event {
+    ///         println!("{} {:?}", report.client_order_id, report.order_status);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn event(&mut self) -> crate::error::Result<Option<Event>> {
+        loop {
+            if self.reconnect_failed {
+                warn!("Reconnecting in {:?}", self.backoff);
+                self.reconnect_after_backoff().await?;
+            } else if self.expired.swap(false, Ordering::SeqCst) {
+                self.reconnect().await?;
+            }
+
+            let stream = match self.stream.as_mut() {
+                Some(stream) => stream,
+                None => return Ok(None),
+            };
+
+            let keep_alive_failed = self.keep_alive_failed.notified();
+
+            let event = tokio::select! {
+                event = stream.event() => event.map(|event| event.map(|(_, event)| event)),
+                // The keepalive task sets `expired` before notifying.
+                _ = keep_alive_failed => continue,
+            };
+
+            match event {
+                Ok(Some(Event::UserData(UserDataEvent::ListenKeyExpired(_)))) => {
+                    warn!("Listen key expired; reconnecting");
+                    self.reconnect().await?;
+                }
+                Ok(Some(event)) => {
+                    self.backoff = ReconnectPolicy::default().initial_delay;
+                    return Ok(Some(event));
+                }
+                Ok(None) => {
+                    warn!("Socket closed; reconnecting in {:?}", self.backoff);
+                    self.reconnect_after_backoff().await?;
+                }
+                Err(e) => {
+                    warn!("{}; reconnecting in {:?}", e, self.backoff);
+                    self.reconnect_after_backoff().await?;
+                }
+            }
+        }
+    }
+    /// The listen key currently in use.
+    pub fn listen_key(&self) -> &str {
+        &self.listen_key
+    }
+    /// Change how often the listen key is kept alive.
+    pub fn set_keep_alive_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+        self.spawn_keep_alive();
+    }
+    /// Close the socket and the listen key.
+    pub async fn shutdown(mut self) -> crate::error::Result<()> {
+        if let Some(handle) = self.keep_alive.take() {
+            handle.abort();
+        }
+        if let Some(mut stream) = self.stream.take() {
+            // The server may already have closed the socket.
+            let _ = stream.close(None).await;
+        }
+        let listen_key = std::mem::take(&mut self.listen_key);
+        self.client.close_stream(&listen_key).text().await?;
+        Ok(())
+    }
+
+    /// Replace the socket and listen key; the current ones are kept if this fails.
+    async fn reconnect(&mut self) -> crate::error::Result<()> {
+        // The listen key is about to be replaced, or is no longer valid.
+        if let Some(handle) = self.keep_alive.take() {
+            handle.abort();
+        }
+        self.reconnect_failed = true;
+
+        // Returns the current listen key if it is still active.
+        let listen_key = self.client.start_stream().listen_key().await?.listen_key;
+        let stream = WebSocketStream::connect(Channel::UserData(&listen_key), self.url.clone()).await?;
+
+        if let Some(mut stream) = self.stream.replace(stream) {
+            let _ = stream.close(None).await;
+        }
+        self.listen_key = listen_key;
+        self.expired.store(false, Ordering::SeqCst);
+        self.reconnect_failed = false;
+        self.spawn_keep_alive();
+        Ok(())
+    }
+
+    async fn reconnect_after_backoff(&mut self) -> crate::error::Result<()> {
+        let policy = ReconnectPolicy::default();
+
+        sleep(self.backoff).await;
+        self.backoff = self.backoff.mul_f64(policy.multiplier).min(policy.max_delay);
+        self.reconnect().await
+    }
+
+    fn spawn_keep_alive(&mut self) {
+        if let Some(handle) = self.keep_alive.take() {
+            handle.abort();
+        }
+
+        let client = self.client.clone();
+        let listen_key = self.listen_key.clone();
+        let interval = self.interval;
+        let expired = self.expired.clone();
+        let keep_alive_failed = self.keep_alive_failed.clone();
+
+        self.keep_alive = Some(tokio::spawn(async move {
+            loop {
+                sleep(interval).await;
+                if let Err(e) = client.keep_alive(&listen_key).text().await {
+                    warn!("Keepalive failed: {}", e);
+                    expired.store(true, Ordering::SeqCst);
+                    keep_alive_failed.notify_one();
+                    return;
+                }
+            }
+        }));
+    }
+}
+
+impl<C: ListenKeyClient> Drop for UserDataStream<C> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.keep_alive.take() {
+            handle.abort();
+        }
+        // Already shut down.
+        if self.listen_key.is_empty() {
+            return;
+        }
+
+        if let Ok(runtime) = Handle::try_current() {
+            let client = self.client.clone();
+            let listen_key = std::mem::take(&mut self.listen_key);
+
+            runtime.spawn(async move {
+                if let Err(e) = client.close_stream(&listen_key).text().await {
+                    warn!("Failed to close listen key: {}", e);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::SinkExt;
+    use std::sync::atomic::AtomicUsize;
+    use async_tungstenite::tokio::accept_async;
+    use async_tungstenite::tungstenite::Message;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// REST API handing out the listen key `key`, failing the second time it is asked for one.
+    async fn rest_api() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let starts = Arc::new(AtomicUsize::new(0));
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let starts = starts.clone();
+
+                tokio::spawn(async move {
+                    let mut request = vec![0; 4096];
+                    let read = socket.read(&mut request).await.unwrap_or(0);
+                    let start = request[..read].starts_with(b"POST");
+
+                    let (status, body) = match start && starts.fetch_add(1, Ordering::SeqCst) == 1 {
+                        true => ("503 Service Unavailable", "{}"),
+                        false if start => ("200 OK", r#"{"listenKey":"key"}"#),
+                        false => ("200 OK", "{}"),
+                    };
+                    let response = format!(
+                        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        status, body.len(), body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        url
+    }
+
+    /// User data streams sending one balance update each, then closing.
+    async fn streams() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let mut socket = accept_async(socket).await.unwrap();
+                let update = r#"{"stream":"key","data":{"e":"balanceUpdate","E":1573200697110,"a":"BTC","d":"100.00000000","T":1573200697068}}"#;
+
+                socket.send(Message::Text(update.into())).await.unwrap();
+                socket.close(None).await.unwrap();
+            }
+        });
+        url
+    }
+
+    fn is_balance_update(event: Option<Event>) -> bool {
+        matches!(event, Some(Event::UserData(UserDataEvent::BalanceUpdate(_))))
+    }
+
+    #[tokio::test]
+    async fn resumes_after_a_failed_reconnect() {
+        let client = UserDataClient::connect("<api-key>", rest_api().await).unwrap();
+        let mut stream = UserDataStream::connect(client, streams().await).await.unwrap();
+
+        assert!(is_balance_update(stream.event().await.unwrap()));
+        // The socket closed and the listen key can't be obtained.
+        assert!(stream.event().await.is_err());
+        assert!(is_balance_update(stream.event().await.unwrap()));
+    }
+}
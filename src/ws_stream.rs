@@ -18,17 +18,23 @@ use futures::{
 use tokio::net::TcpStream;
 use tokio_native_tls::TlsStream;
 
+use crate::environment::WsUrl;
 use crate::error::{Error, Kind, WsCloseError};
+use crate::model::Event;
 use crate::param::Interval;
+use log::warn;
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::VecDeque;
 use std::fmt;
+use tokio::time::{sleep, Duration};
+use rand::Rng;
 
 /// wss://stream.binance.us:9443
-pub const BINANCE_US_WSS_URL: &'static str = "wss://stream.binance.us:9443";
+pub const BINANCE_US_WSS_URL: &str = "wss://stream.binance.us:9443";
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 pub enum Channel<'c> {
     AggTrade(&'c str),
     Depth(&'c str, Speed),
@@ -43,6 +49,92 @@ pub enum Channel<'c> {
     PartialDepth(&'c str, Level, Speed),
     /// The only channel that takes a listen-key instead of a symbol
     UserData(&'c str),
+    /// Futures only: mark price and funding rate of a symbol, every second.
+    MarkPrice(&'c str),
+    /// Futures only: mark price and funding rate of every symbol, every 3 seconds.
+    AllMarkPrices,
+    /// Futures only: liquidation orders of a symbol.
+    LiquidationOrder(&'c str),
+    /// Futures only: liquidation orders of every symbol.
+    AllLiquidationOrders,
+    /// Futures only: klines of a pair for one contract type.
+    ContinuousKline(&'c str, ContractType, Interval),
+    /// COIN-M futures only: index price of a pair.
+    IndexPrice(&'c str),
+    /// USDⓈ-M futures only: composition of a composite index symbol.
+    CompositeIndex(&'c str),
+}
+
+impl<'c> Channel<'c> {
+    /// Parses a stream name, as found in the `stream` field of combined messages,
+    /// back into a channel. Symbols are left lowercase as Binance sends them.
+    /// # Example
+    ///
+    /// ```
+    /// use tokio_binance::{Channel, Interval};
+    ///
+    /// let channel = Channel::from_stream("bnbusdt@kline_1m").unwrap();
+    /// assert_eq!(channel, Channel::Kline("BNBUSDT", Interval::OneMinute));
+    ///
+    /// use tokio_binance::ContractType;
+    ///
+    /// let channel = Channel::from_stream("btcusd_current_quarter@continuousKline_1h").unwrap();
+    /// assert_eq!(channel, Channel::ContinuousKline("BTCUSD", ContractType::CurrentQuarter, Interval::OneHour));
+    /// ```
+    pub fn from_stream(stream: &'c str) -> Option<Self> {
+        match stream {
+            "!miniTicker@arr" => return Some(Self::AllMiniTickers),
+            "!ticker@arr" => return Some(Self::AllTickers),
+            "!bookTicker" => return Some(Self::AllBookTickers),
+            "!markPrice@arr" => return Some(Self::AllMarkPrices),
+            "!forceOrder@arr" => return Some(Self::AllLiquidationOrders),
+            _ => (),
+        }
+
+        let (symbol, name) = match stream.find('@') {
+            Some(index) => (&stream[..index], &stream[index + 1..]),
+            None => return Some(Self::UserData(stream)),
+        };
+
+        let channel = match name {
+            "aggTrade" => Self::AggTrade(symbol),
+            "trade" => Self::Trade(symbol),
+            "miniTicker" => Self::MiniTicker(symbol),
+            "ticker" => Self::Ticker(symbol),
+            "bookTicker" => Self::BookTicker(symbol),
+            "markPrice@1s" => Self::MarkPrice(symbol),
+            "forceOrder" => Self::LiquidationOrder(symbol),
+            "indexPrice" => Self::IndexPrice(symbol),
+            "compositeIndex" => Self::CompositeIndex(symbol),
+            _ if name.starts_with("kline_") => Self::Kline(symbol, from_str(&name[6..])?),
+            _ if name.starts_with("continuousKline_") => {
+                // The contract type contains underscores itself, the pair never does.
+                let (pair, contract_type) = symbol.split_once('_')?;
+                Self::ContinuousKline(pair, from_str(contract_type)?, from_str(&name[16..])?)
+            }
+            _ if name.starts_with("depth") => {
+                let mut parts = name[5..].splitn(2, '@');
+                let level = parts.next().unwrap_or("");
+                let speed = match parts.next() {
+                    Some(speed) => from_str(speed)?,
+                    None => Speed::ThousandMillis,
+                };
+
+                if level.is_empty() {
+                    Self::Depth(symbol, speed)
+                } else {
+                    Self::PartialDepth(symbol, from_str(level)?, speed)
+                }
+            }
+            _ => return None,
+        };
+
+        Some(channel)
+    }
+}
+
+fn from_str<T: DeserializeOwned>(value: &str) -> Option<T> {
+    serde_json::from_value(Value::String(value.into())).ok()
 }
 
 impl<'c> fmt::Display for Channel<'c> {
@@ -86,29 +178,54 @@ impl<'c> fmt::Display for Channel<'c> {
                 )
             }
             Self::UserData(listen_key) => write!(f, "{}", listen_key),
+            Self::MarkPrice(symbol) => write!(f, "{}", symbol.to_lowercase() + "@markPrice@1s"),
+            Self::AllMarkPrices => write!(f, "!markPrice@arr"),
+            Self::LiquidationOrder(symbol) => write!(f, "{}", symbol.to_lowercase() + "@forceOrder"),
+            Self::AllLiquidationOrders => write!(f, "!forceOrder@arr"),
+            Self::ContinuousKline(pair, contract_type, interval) => {
+                let contract_type = serde_json::to_value(contract_type).unwrap();
+                let interval = serde_json::to_value(interval).unwrap();
+                write!(
+                    f,
+                    "{}",
+                    pair.to_lowercase()
+                        + "_"
+                        + contract_type.as_str().unwrap()
+                        + "@continuousKline_"
+                        + interval.as_str().unwrap()
+                )
+            }
+            Self::IndexPrice(pair) => write!(f, "{}", pair.to_lowercase() + "@indexPrice"),
+            Self::CompositeIndex(symbol) => write!(f, "{}", symbol.to_lowercase() + "@compositeIndex"),
         }
     }
 }
 
+impl<'c, 'o> PartialEq<Channel<'o>> for Channel<'c> {
+    fn eq(&self, other: &Channel<'o>) -> bool {
+        self.to_string().as_str() == other.to_string().as_str()
+    }
+}
+
 impl<'a, 'c> PartialEq<&'a str> for Channel<'c> {
     fn eq(&self, other: &&str) -> bool {
-        self.to_string() == *other
+        self.to_string().as_str() == *other
     }
 }
 
 impl<'c> PartialEq<String> for Channel<'c> {
     fn eq(&self, other: &String) -> bool {
-        self.to_string() == *other
+        self.to_string().as_str() == other.as_str()
     }
 }
 
 impl<'c> PartialEq<Value> for Channel<'c> {
     fn eq(&self, other: &Value) -> bool {
-        self.to_string() == *other
+        other.as_str() == Some(self.to_string().as_str())
     }
 }
 
-#[derive(Copy, Clone, Serialize)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub enum Level {
     #[serde(rename = "5")]
     Five,
@@ -118,7 +235,7 @@ pub enum Level {
     Twenty,
 }
 
-#[derive(Copy, Clone, Serialize)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub enum Speed {
     #[serde(rename = "100ms")]
     HundredMillis,
@@ -126,6 +243,22 @@ pub enum Speed {
     ThousandMillis,
 }
 
+/// Futures contract of a `Channel::ContinuousKline`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractType {
+    #[serde(alias = "PERPETUAL")]
+    Perpetual,
+    #[serde(alias = "CURRENT_MONTH")]
+    CurrentMonth,
+    #[serde(alias = "NEXT_MONTH")]
+    NextMonth,
+    #[serde(alias = "CURRENT_QUARTER")]
+    CurrentQuarter,
+    #[serde(alias = "NEXT_QUARTER")]
+    NextQuarter,
+}
+
 #[derive(Serialize)]
 struct SubscribeMessage<'a> {
     method: &'a str,
@@ -138,10 +271,39 @@ type InnerStream = (
     Response,
 );
 
+/// Backoff used by a reconnecting [`WebSocketStream`].
+#[derive(Copy, Clone, Debug)]
+pub struct ReconnectPolicy {
+    /// Delay after the first failed attempt.
+    pub initial_delay: Duration,
+    /// Upper bound of the delay between two attempts.
+    pub max_delay: Duration,
+    /// Factor the delay grows by after each failed attempt.
+    pub multiplier: f64,
+    /// Give up after this many attempts; never gives up if `None`.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+            max_attempts: None,
+        }
+    }
+}
+
 /// Websocket stream for the various binance channels aka streams.
 pub struct WebSocketStream {
     inner: InnerStream,
     id: u64,
+    stream: String,
+    urls: Vec<String>,
+    channels: Vec<String>,
+    reconnect: Option<ReconnectPolicy>,
+    reconnected: VecDeque<String>,
 }
 
 impl WebSocketStream {
@@ -158,26 +320,60 @@ impl WebSocketStream {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn connect<U: Into<String>>(
+    pub async fn connect<U: Into<WsUrl>>(
         channel: Channel<'_>,
         url: U,
     ) -> crate::error::Result<Self> {
-        let url = url.into() + "/ws/" + &channel.to_string();
-
-        let inner = connect_async(url).await?;
-        let mut stream = Self { inner, id: 0 };
+        let urls = url.into().urls;
+        let channel = channel.to_string();
 
-        let message = SubscribeMessage {
-            method: "SET_PROPERTY",
-            params: &["combined".into(), true.into()],
-            id: stream.id,
+        let inner = connect_any(&urls, &channel).await?;
+        let mut stream = Self {
+            inner,
+            id: 0,
+            stream: String::new(),
+            urls,
+            channels: vec![channel],
+            reconnect: None,
+            reconnected: VecDeque::new(),
         };
-        let message = serde_json::to_string(&message)?;
-        stream.send(Message::Text(message)).await?;
-        stream.id += 1;
 
+        stream.send_msg("SET_PROPERTY", &["combined".into(), true.into()]).await?;
         Ok(stream)
     }
+    /// Reconnect automatically whenever the connection fails or is closed by the server,
+    /// e.g. by the forced disconnect after 24 hours. Every channel subscribed to is
+    /// subscribed to again on the new connection.
+    ///
+    /// After a reconnect, `text` and `json` return a `{"reconnected": <attempts>}` message,
+    /// and `event` returns `Event::Reconnected` once for every channel, since messages
+    /// may have been missed in between.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WebSocketStream, BINANCE_US_WSS_URL, Channel};
+    /// use tokio_binance::ReconnectPolicy;
+    /// use tokio_binance::model::Event;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let channel = Channel::Ticker("BNBUSDT");
+    /// let mut stream = WebSocketStream::connect(channel, BINANCE_US_WSS_URL)
+    ///     .await?
+    ///     .with_reconnect(ReconnectPolicy::default());
+    ///
+    /// while let Some((channel, event)) = stream.event().await? {
+    ///     if let Event::Reconnected = event {
+    ///         println!("{} may have missed messages", channel);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
     /// Helper method for getting messages as text.
     /// # Example
     ///
@@ -194,36 +390,24 @@ impl WebSocketStream {
     /// # }
     /// ```
     pub async fn text(&mut self) -> crate::error::Result<Option<String>> {
-        match self.try_next().await? {
-            Some(msg) => match msg {
-                Message::Text(text) => Ok(Some(text)),
-                Message::Ping(ref value) => {
-                    self.send(Message::Pong(value.clone())).await?;
-                    let ping = serde_json::json!({
-                        "ping": msg.into_text()?,
-                    });
-                    Ok(Some(serde_json::to_string(&ping)?))
-                }
-                Message::Pong(ref value) => {
-                    self.send(Message::Ping(value.clone())).await?;
-                    let pong = serde_json::json!({
-                        "pong": msg.into_text()?,
-                    });
-                    Ok(Some(serde_json::to_string(&pong)?))
-                }
-                Message::Binary(_) => Ok(Some(msg.into_text()?)),
-                Message::Close(Some(frame)) => {
-                    Err(WsCloseError::new(frame.code, frame.reason).into())
+        let result = self.read_text().await;
+
+        match (result, self.reconnect) {
+            (Ok(Some(text)), _) => Ok(Some(text)),
+            (result, None) => result,
+            (result, Some(policy)) => {
+                if let Err(e) = result {
+                    warn!("{}; reconnecting", e);
                 }
-                Message::Close(None) => Err(WsCloseError::new(
-                    CloseCode::Abnormal,
-                    "Close message with no frame received",
-                )
-                .into()),
-            },
-            None => Ok(None),
+                let attempts = self.reconnect(policy).await?;
+                let reconnected = serde_json::json!({
+                    "reconnected": attempts,
+                });
+                Ok(Some(serde_json::to_string(&reconnected)?))
+            }
         }
     }
+
     /// Helper method for getting messages as a serde deserializable.
     /// # Example
     ///
@@ -250,6 +434,64 @@ impl WebSocketStream {
             None => Ok(None),
         }
     }
+    /// Helper method for getting messages decoded into an [`Event`] along with
+    /// the channel they were sent on. Responses to subscribe requests are skipped
+    /// and payloads that can't be decoded are returned as `Event::Unknown`.
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio_binance::{WebSocketStream, BINANCE_US_WSS_URL, Channel};
+    /// use tokio_binance::model::Event;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let channel = Channel::Ticker("BNBUSDT");
+    /// # let mut stream = WebSocketStream::connect(channel, BINANCE_US_WSS_URL).await?;
+    /// while let Some((channel, event)) = stream.event().await? {
+    ///     if let Event::Ticker(ticker) = event {
+    ///         println!("{}: {}", channel, ticker.last_price);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn event(&mut self) -> crate::error::Result<Option<(Channel<'_>, Event)>> {
+        let data = loop {
+            if let Some(stream) = self.reconnected.pop_front() {
+                self.stream = stream;
+                break None;
+            }
+
+            let mut value = match self.json::<Value>().await? {
+                Some(value) => value,
+                None => return Ok(None),
+            };
+
+            if value.get("reconnected").is_some() {
+                self.reconnected.extend(self.channels.iter().cloned());
+                continue;
+            }
+
+            let stream = match value["stream"].take() {
+                Value::String(stream) => stream,
+                _ => continue,
+            };
+
+            if Channel::from_stream(&stream).is_some() {
+                self.stream = stream;
+                break Some(value["data"].take());
+            }
+            warn!("Unknown stream {}", stream);
+        };
+
+        // The stream name was already validated above.
+        let channel = Channel::from_stream(&self.stream).unwrap();
+        let event = match data {
+            Some(data) => Event::decode(&channel, data),
+            None => Event::Reconnected,
+        };
+        Ok(Some((channel, event)))
+    }
     /// Subscribe to one or more channels aka streams.
     /// # Example
     ///
@@ -271,7 +513,16 @@ impl WebSocketStream {
     /// # }
     /// ```
     pub async fn subscribe(&mut self, channels: &[Channel<'_>]) -> crate::error::Result<()> {
-        self.send_msg("SUBSCRIBE", channels).await
+        let params = to_params(channels);
+
+        for param in &params {
+            let name = param.as_str().unwrap_or_default();
+            if !self.channels.iter().any(|channel| channel == name) {
+                self.channels.push(name.into());
+            }
+        }
+
+        self.send_msg("SUBSCRIBE", &params).await
     }
     /// Unsubscribe from one or more channels aka streams.
     /// # Example
@@ -293,7 +544,9 @@ impl WebSocketStream {
     /// # }
     /// ```
     pub async fn unsubscribe(&mut self, channels: &[Channel<'_>]) -> crate::error::Result<()> {
-        self.send_msg("UNSUBSCRIBE", channels).await
+        let params = to_params(channels);
+        self.channels.retain(|channel| !params.iter().any(|param| param == channel));
+        self.send_msg("UNSUBSCRIBE", &params).await
     }
     /// Returns a shared reference to the inner stream.
     pub fn get_ref(&self) -> &InnerStream {
@@ -309,19 +562,42 @@ impl WebSocketStream {
         Ok(())
     }
 
-    async fn send_msg(
-        &mut self,
-        method: &str,
-        channels: &[Channel<'_>],
-    ) -> crate::error::Result<()> {
-        let params: Vec<_> = channels
-            .iter()
-            .map(|channel| Value::String(channel.to_string()))
-            .collect();
+    async fn read_text(&mut self) -> crate::error::Result<Option<String>> {
+        match self.try_next().await? {
+            Some(msg) => match msg {
+                Message::Text(text) => Ok(Some(text)),
+                Message::Ping(ref value) => {
+                    self.send(Message::Pong(value.clone())).await?;
+                    let ping = serde_json::json!({
+                        "ping": msg.into_text()?,
+                    });
+                    Ok(Some(serde_json::to_string(&ping)?))
+                }
+                Message::Pong(ref value) => {
+                    self.send(Message::Ping(value.clone())).await?;
+                    let pong = serde_json::json!({
+                        "pong": msg.into_text()?,
+                    });
+                    Ok(Some(serde_json::to_string(&pong)?))
+                }
+                Message::Binary(_) => Ok(Some(msg.into_text()?)),
+                Message::Close(Some(frame)) => {
+                    Err(WsCloseError::new(frame.code, frame.reason).into())
+                }
+                Message::Close(None) => Err(WsCloseError::new(
+                    CloseCode::Abnormal,
+                    "Close message with no frame received",
+                )
+                .into()),
+            },
+            None => Ok(None),
+        }
+    }
 
+    async fn send_msg(&mut self, method: &str, params: &[Value]) -> crate::error::Result<()> {
         let message = SubscribeMessage {
             method,
-            params: &params,
+            params,
             id: self.id,
         };
         let message = serde_json::to_string(&message)?;
@@ -329,6 +605,66 @@ impl WebSocketStream {
         self.id += 1;
         Ok(())
     }
+
+    async fn reconnect(&mut self, policy: ReconnectPolicy) -> crate::error::Result<u32> {
+        // The first channel goes into the url, the rest are subscribed to afterwards.
+        let (first, rest) = match self.channels.split_first() {
+            Some((first, rest)) => (first.clone(), rest.to_vec()),
+            None => (self.stream.clone(), Vec::new()),
+        };
+
+        let mut delay = policy.initial_delay;
+        let mut attempts = 0;
+
+        self.inner = loop {
+            attempts += 1;
+
+            match connect_any(&self.urls, &first).await {
+                Ok(inner) => break inner,
+                Err(e) if policy.max_attempts.map_or(true, |max| attempts < max) => {
+                    warn!("Reconnect attempt {} failed: {}", attempts, e);
+                    // Equal jitter: wait between half and all of the current delay.
+                    let jitter = rand::thread_rng().gen_range(0.5..=1.0);
+                    sleep(delay.mul_f64(jitter)).await;
+                    delay = delay.mul_f64(policy.multiplier).min(policy.max_delay);
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        self.send_msg("SET_PROPERTY", &["combined".into(), true.into()]).await?;
+
+        if !rest.is_empty() {
+            let params: Vec<_> = rest.into_iter().map(Value::String).collect();
+            self.send_msg("SUBSCRIBE", &params).await?;
+        }
+
+        Ok(attempts)
+    }
+}
+
+/// Connect to `channel` at the first of `urls` that accepts the connection.
+async fn connect_any(urls: &[String], channel: &str) -> crate::error::Result<InnerStream> {
+    let mut error = None;
+
+    for url in urls {
+        match connect_async(url.clone() + "/ws/" + channel).await {
+            Ok(inner) => return Ok(inner),
+            Err(e) => {
+                warn!("Failed to connect to {}: {}", url, e);
+                error = Some(e);
+            }
+        }
+    }
+
+    Err(error.expect("at least one url").into())
+}
+
+fn to_params(channels: &[Channel<'_>]) -> Vec<Value> {
+    channels
+        .iter()
+        .map(|channel| Value::String(channel.to_string()))
+        .collect()
 }
 
 impl Stream for WebSocketStream {